@@ -1,13 +1,15 @@
 #![allow(unexpected_cfgs)]
 
-use crate::{
-    instructions::{self, RWAInstruction},
-    errors::RWAError,
+use {
     pinocchio::{
-    account_info::AccountInfo, default_panic_handler, msg, no_allocator, program_entrypoint,
-    program_error::ProgramError, pubkey::Pubkey, ProgramResult,
-   },
-   pinocchio_log::log
+        account_info::AccountInfo, default_panic_handler, no_allocator, program_entrypoint,
+        pubkey::Pubkey, ProgramResult,
+    },
+    pinocchio_log::log,
+    crate::{
+        errors::RWAError,
+        instructions::*,
+    },
 };
 
 // This is the entrypoint for the program.
@@ -19,84 +21,227 @@ default_panic_handler!();
 
 #[inline(always)]
 fn process_instruction(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let (ix_disc, instruction_data) = instruction_data
+    // Transfer hook interface instructions use 8 byte discriminators. Their first
+    // bytes (105, 43) are not RWAInstruction values, so the two can't be confused.
+    if let Some((hook_disc, rest)) = instruction_data.split_first_chunk::<8>() {
+        if *hook_disc == TransferHookExecuteInstruction::DISCRIMINATOR {
+            #[cfg(not(feature = "perf"))]
+            log!("TRANSFER_HOOK_EXECUTE");
+            let mut ix = TransferHookExecuteInstruction::try_from((rest, accounts))?;
+            return ix.process(program_id);
+        }
+        if *hook_disc == InitializeExtraAccountMetasInstruction::DISCRIMINATOR {
+            #[cfg(not(feature = "perf"))]
+            log!("INITIALIZE_EXTRA_ACCOUNT_METAS");
+            let mut ix = InitializeExtraAccountMetasInstruction::try_from((rest, accounts))?;
+            return ix.process(program_id);
+        }
+    }
+
+    let (ix_disc, rest) = instruction_data
         .split_first()
         .ok_or(RWAError::InvalidInstructionData)?;
 
-        match ix_disc {
-            0 => {
-                #[cfg(not(feature = "perf"))]
-                log!("INIT_GLOBAL_CONFIG");
-
-                let mut ix = InitGlobalConfigInstruction::try_from((rest, accounts))?;
-                ix.process(program_id)
-                Ok(())
-            },
-
-            1 => {
-                #[cfg(not(feature = "perf"))]
-                log!("UPDATE_GLOBAL_CONFIG");
-                // let mut ix = UpdateGlobalConfigInstruction::try_from((rest, accounts))?;
-                // ix.process(program_id)
-                Ok(())
-            },
+    // Unknown discriminators fail here instead of silently succeeding
+    match RWAInstruction::try_from(*ix_disc)? {
+        //batch processing
+        RWAInstruction::Batch => {
+            #[cfg(not(feature = "perf"))]
+            log!("BATCH_PROCESSING");
+            // Runs atomically, any failing record reverts the whole batch
+            process_batch(accounts, rest, |ix, accounts, data| dispatch(program_id, ix, accounts, data))
+        },
+        ix => dispatch(program_id, ix, accounts, rest),
+    }
+}
 
-            2 => {
-                #[cfg(not(feature = "perf"))]
-                log!("CREATOR_KYC");
-                let mut ix = CreatorKYCInstruction::try_from((rest, accounts))?;
-                ix.process(program_id)
-                Ok(())
-            },
-            3 => {
-                #[cfg(not(feature = "perf"))]
-                log!("VERIFY_CREATOR_KYC");
-                // let mut ix = VerifyCreatorKYCInstruction::try_from((rest, accounts))?;
-                // ix.process(program_id)
-                Ok(())
-            },
-            4 => {
-                #[cfg(not(feature = "perf"))]
-                log!("INIT_TOKEN_CONFIGURATION");
-                let mut ix = InitTokenConfigInstruction::try_from((rest, accounts))?;
-                ix.process(program_id)
-                Ok(())
-            },
-            5 => {
-                #[cfg(not(feature = "perf"))]
-                log!("VERIFY_TOKEN_CONFIGURATION");
-                // let mut ix = VerifyTokenConfigInstruction::try_from((rest, accounts))?;
-                // ix.process(program_id)
-                Ok(())
-            },
-            6 => {
-                #[cfg(not(feature = "perf"))]
-                log!("CREATE_RWA_MINT & METADATA");
-                // let mut ix = CreateRWAInstruction::try_from((rest, accounts))?;
-                // ix.process(program_id)
-                Ok(())
-            },
-            7 => {
-                #[cfg(not(feature = "perf"))]
-                log!("MINT_RWA_TOKEN");
-                // let mut ix = MintRWAInstruction::try_from((rest, accounts))?;
-                // ix.process(program_id)
-                Ok(())
-            },
-            //batch processing 
-            255 => {
-                #[cfg(not(feature = "perf"))]
-                log!("BATCH PROCESSING - FEAT: COMING SOON");
-                Ok(())
-            },
-            _ => {
-                log!("FALLBACK INSTRUCTION");
-                Ok(())
-            }
-        }
-       }
-}
\ No newline at end of file
+#[inline(always)]
+fn dispatch(
+    program_id: &Pubkey,
+    ix: RWAInstruction,
+    accounts: &[AccountInfo],
+    rest: &[u8],
+) -> ProgramResult {
+    match ix {
+        RWAInstruction::InitGlobalConfig => {
+            #[cfg(not(feature = "perf"))]
+            log!("INIT_GLOBAL_CONFIG");
+            let mut ix = InitGlobalConfigInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::UpdateGlobalConfig => {
+            #[cfg(not(feature = "perf"))]
+            log!("UPDATE_GLOBAL_CONFIG");
+            let mut ix = UpdateGlobalConfigInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::CreatorKYC => {
+            #[cfg(not(feature = "perf"))]
+            log!("CREATOR_KYC");
+            let mut ix = CreatorKYCInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::VerifyCreatorKYC => {
+            #[cfg(not(feature = "perf"))]
+            log!("VERIFY_CREATOR_KYC");
+            let mut ix = VerifyCreatorKYCInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::InitTokenConfig => {
+            #[cfg(not(feature = "perf"))]
+            log!("INIT_TOKEN_CONFIGURATION");
+            let mut ix = InitTokenConfigInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::VerifyTokenConfig => {
+            #[cfg(not(feature = "perf"))]
+            log!("VERIFY_TOKEN_CONFIGURATION");
+            let mut ix = VerifyTokenConfigInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::CreateRWA => {
+            #[cfg(not(feature = "perf"))]
+            log!("CREATE_RWA_MINT & METADATA");
+            let mut ix = CreateRWAInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::MintRWA => {
+            #[cfg(not(feature = "perf"))]
+            log!("MINT_RWA_TOKEN");
+            let mut ix = MintRWAInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::ProposeAuthority => {
+            #[cfg(not(feature = "perf"))]
+            log!("PROPOSE_AUTHORITY");
+            let mut ix = ProposeAuthorityInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::AcceptAuthority => {
+            #[cfg(not(feature = "perf"))]
+            log!("ACCEPT_AUTHORITY");
+            let mut ix = AcceptAuthorityInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::CancelAuthority => {
+            #[cfg(not(feature = "perf"))]
+            log!("CANCEL_AUTHORITY");
+            let mut ix = CancelAuthorityInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::Redeem => {
+            #[cfg(not(feature = "perf"))]
+            log!("REDEEM");
+            let mut ix = RedeemInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::SettleRedemption => {
+            #[cfg(not(feature = "perf"))]
+            log!("SETTLE_REDEMPTION");
+            let mut ix = SettleRedemptionInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::WithdrawFees => {
+            #[cfg(not(feature = "perf"))]
+            log!("WITHDRAW_FEES");
+            let mut ix = WithdrawFeesInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::SetTokenConfigFees => {
+            #[cfg(not(feature = "perf"))]
+            log!("SET_TOKEN_CONFIG_FEES");
+            let mut ix = SetTokenConfigFeesInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::HarvestAndWithdrawWithheld => {
+            #[cfg(not(feature = "perf"))]
+            log!("HARVEST_AND_WITHDRAW_WITHHELD");
+            let mut ix = HarvestAndWithdrawWithheldInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::SubmitInvestorKYC => {
+            #[cfg(not(feature = "perf"))]
+            log!("SUBMIT_INVESTOR_KYC");
+            let mut ix = SubmitInvestorKYCInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::ApproveInvestorKYC => {
+            #[cfg(not(feature = "perf"))]
+            log!("APPROVE_INVESTOR_KYC");
+            let mut ix = ApproveInvestorKYCInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::RevokeInvestorKYC => {
+            #[cfg(not(feature = "perf"))]
+            log!("REVOKE_INVESTOR_KYC");
+            let mut ix = RevokeInvestorKYCInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::SetTokenConfigRules => {
+            #[cfg(not(feature = "perf"))]
+            log!("SET_TOKEN_CONFIG_RULES");
+            let mut ix = SetTokenConfigRulesInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::ResubmitCreatorKYC => {
+            #[cfg(not(feature = "perf"))]
+            log!("RESUBMIT_CREATOR_KYC");
+            let mut ix = ResubmitCreatorKYCInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::CloseCreatorKYC => {
+            #[cfg(not(feature = "perf"))]
+            log!("CLOSE_CREATOR_KYC");
+            let mut ix = CloseCreatorKYCInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::CloseTokenConfig => {
+            #[cfg(not(feature = "perf"))]
+            log!("CLOSE_TOKEN_CONFIG");
+            let mut ix = CloseTokenConfigInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::UpdateRWAMetadata => {
+            #[cfg(not(feature = "perf"))]
+            log!("UPDATE_RWA_METADATA");
+            let mut ix = UpdateRWAMetadataInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::MakeImmutable => {
+            #[cfg(not(feature = "perf"))]
+            log!("MAKE_IMMUTABLE");
+            let mut ix = MakeImmutableInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::ThawInvestorAccount => {
+            #[cfg(not(feature = "perf"))]
+            log!("THAW_INVESTOR_ACCOUNT");
+            let mut ix = ThawInvestorAccountInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::FreezeHolder => {
+            #[cfg(not(feature = "perf"))]
+            log!("FREEZE_HOLDER");
+            let mut ix = FreezeHolderInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::ThawHolder => {
+            #[cfg(not(feature = "perf"))]
+            log!("THAW_HOLDER");
+            let mut ix = ThawHolderInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        RWAInstruction::ForceTransfer => {
+            #[cfg(not(feature = "perf"))]
+            log!("FORCE_TRANSFER");
+            let mut ix = ForceTransferInstruction::try_from((rest, accounts))?;
+            ix.process(program_id)
+        },
+        // Only valid as the outer instruction, handled by process_instruction
+        RWAInstruction::Batch => Err(RWAError::InvalidInstructionData.into()),
+    }
+}
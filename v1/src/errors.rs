@@ -30,7 +30,76 @@ pub enum RWAError {
     UninitialisedAccount,
 
     #[error("Not Enough Accounts passed that were required by the ix...")]
-    NotEnoughAccountKeys
+    NotEnoughAccountKeys,
+
+    #[error("Signer is not the authority for this account")]
+    Unauthorized,
+
+    #[error("Fee basis points exceed 10_000")]
+    InvalidFeeBps,
+
+    #[error("Decimals exceed the maximum allowed")]
+    InvalidDecimals,
+
+    #[error("No authority transfer is pending")]
+    NoPendingAuthority,
+
+    #[error("KYC record is not in a status that allows this")]
+    InvalidKycStatus,
+
+    #[error("Token config is not in a status that allows this")]
+    InvalidTokenConfigStatus,
+
+    #[error("KYC record is not approved")]
+    KycNotApproved,
+
+    #[error("Token config is not active")]
+    TokenConfigNotActive,
+
+    #[error("A mint was already created for this token config")]
+    MintAlreadyCreated,
+
+    #[error("Minting would exceed the max supply")]
+    MaxSupplyExceeded,
+
+    #[error("Redemption request was already settled or rejected")]
+    RedemptionNotPending,
+
+    #[error("Program is paused")]
+    ProgramPaused,
+
+    #[error("Program is not open yet")]
+    NotYetOpen,
+
+    #[error("Investor KYC is not approved")]
+    InvestorKycNotApproved,
+
+    #[error("Transfer hook called outside of a Token-2022 transfer")]
+    NotTransferring,
+
+    #[error("Investor jurisdiction is not allowed for this asset")]
+    JurisdictionNotAllowed,
+
+    #[error("Investor accreditation tier is below the asset minimum")]
+    AccreditationTooLow,
+
+    #[error("KYC approval has expired")]
+    KycExpired,
+
+    #[error("Account is still referenced and can't be closed")]
+    AccountInUse,
+
+    #[error("Mint supply must be zero")]
+    SupplyNotZero,
+
+    #[error("Token metadata is immutable")]
+    MetadataImmutable,
+
+    #[error("Holder is under a legal hold")]
+    HolderFrozen,
+
+    #[error("Asset was created without a permanent delegate")]
+    PermanentDelegateNotSet,
 }
 
 
@@ -52,7 +121,30 @@ impl TryFrom<u32> for RWAError {
             4 => Ok(RWAError::InvalidAddress),
             5 => Ok(RWAError::InvalidAccountData),
             6 => Ok(RWAError::UninitialisedAccount),
-            7 => Ok(RWAError::NotEnoughAccountKeys)
+            7 => Ok(RWAError::NotEnoughAccountKeys),
+            8 => Ok(RWAError::Unauthorized),
+            9 => Ok(RWAError::InvalidFeeBps),
+            10 => Ok(RWAError::InvalidDecimals),
+            11 => Ok(RWAError::NoPendingAuthority),
+            12 => Ok(RWAError::InvalidKycStatus),
+            13 => Ok(RWAError::InvalidTokenConfigStatus),
+            14 => Ok(RWAError::KycNotApproved),
+            15 => Ok(RWAError::TokenConfigNotActive),
+            16 => Ok(RWAError::MintAlreadyCreated),
+            17 => Ok(RWAError::MaxSupplyExceeded),
+            18 => Ok(RWAError::RedemptionNotPending),
+            19 => Ok(RWAError::ProgramPaused),
+            20 => Ok(RWAError::NotYetOpen),
+            21 => Ok(RWAError::InvestorKycNotApproved),
+            22 => Ok(RWAError::NotTransferring),
+            23 => Ok(RWAError::JurisdictionNotAllowed),
+            24 => Ok(RWAError::AccreditationTooLow),
+            25 => Ok(RWAError::KycExpired),
+            26 => Ok(RWAError::AccountInUse),
+            27 => Ok(RWAError::SupplyNotZero),
+            28 => Ok(RWAError::MetadataImmutable),
+            29 => Ok(RWAError::HolderFrozen),
+            30 => Ok(RWAError::PermanentDelegateNotSet),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
@@ -68,7 +160,30 @@ impl ToStr for RWAError {
             RWAError::InvalidAddress => "Invalid address",
             RWAError::InvalidAccountData => "Invalid account data",
             RWAError::UninitialisedAccount => "The pda doesn't exist onchain because it's balance is 0",
-            RWAError::NotEnoughAccountKeys => "Not Enough Accounts passed that were required by the ix..."
+            RWAError::NotEnoughAccountKeys => "Not Enough Accounts passed that were required by the ix...",
+            RWAError::Unauthorized => "Signer is not the authority for this account",
+            RWAError::InvalidFeeBps => "Fee basis points exceed 10_000",
+            RWAError::InvalidDecimals => "Decimals exceed the maximum allowed",
+            RWAError::NoPendingAuthority => "No authority transfer is pending",
+            RWAError::InvalidKycStatus => "KYC record is not in a status that allows this",
+            RWAError::InvalidTokenConfigStatus => "Token config is not in a status that allows this",
+            RWAError::KycNotApproved => "KYC record is not approved",
+            RWAError::TokenConfigNotActive => "Token config is not active",
+            RWAError::MintAlreadyCreated => "A mint was already created for this token config",
+            RWAError::MaxSupplyExceeded => "Minting would exceed the max supply",
+            RWAError::RedemptionNotPending => "Redemption request was already settled or rejected",
+            RWAError::ProgramPaused => "Program is paused",
+            RWAError::NotYetOpen => "Program is not open yet",
+            RWAError::InvestorKycNotApproved => "Investor KYC is not approved",
+            RWAError::NotTransferring => "Transfer hook called outside of a Token-2022 transfer",
+            RWAError::JurisdictionNotAllowed => "Investor jurisdiction is not allowed for this asset",
+            RWAError::AccreditationTooLow => "Investor accreditation tier is below the asset minimum",
+            RWAError::KycExpired => "KYC approval has expired",
+            RWAError::AccountInUse => "Account is still referenced and can't be closed",
+            RWAError::SupplyNotZero => "Mint supply must be zero",
+            RWAError::MetadataImmutable => "Token metadata is immutable",
+            RWAError::HolderFrozen => "Holder is under a legal hold",
+            RWAError::PermanentDelegateNotSet => "Asset was created without a permanent delegate",
         }
     }
 }
\ No newline at end of file
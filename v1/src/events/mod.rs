@@ -1 +1,310 @@
-//
+use {
+    pinocchio::{log::sol_log_data, pubkey::Pubkey},
+    crate::utils::{to_bytes, DataLen},
+};
+
+/// Fixed-layout events for indexers.
+///
+/// Each event is logged through `sol_log_data` as two fields: the one byte
+/// `DISCRIMINATOR` followed by the raw struct bytes. Structs are `packed`
+/// so the logged bytes never contain padding.
+pub trait Event: DataLen + Sized {
+    const DISCRIMINATOR: u8;
+
+    #[inline(always)]
+    fn emit(&self) {
+        sol_log_data(&[&[Self::DISCRIMINATOR], unsafe { to_bytes(self) }]);
+    }
+}
+
+macro_rules! impl_event {
+    ($event:ty, $discriminator:expr) => {
+        impl DataLen for $event {
+            const LEN: usize = core::mem::size_of::<$event>();
+        }
+
+        impl Event for $event {
+            const DISCRIMINATOR: u8 = $discriminator;
+        }
+    };
+}
+
+#[repr(C, packed)]
+pub struct GlobalConfigInitialized {
+    pub config_authority: Pubkey,
+    pub kyc_authority: Pubkey,
+    pub open_time: u64,
+    pub max_decimal: u8,
+    pub fees_bps: u16,
+    pub active: bool,
+}
+impl_event!(GlobalConfigInitialized, 0);
+
+#[repr(C, packed)]
+pub struct GlobalConfigUpdated {
+    pub config_authority: Pubkey,
+    pub open_time: u64,
+    pub mask: u8,
+    pub max_decimal: u8,
+    pub fees_bps: u16,
+    pub active: bool,
+}
+impl_event!(GlobalConfigUpdated, 1);
+
+#[repr(C, packed)]
+pub struct AuthorityProposed {
+    pub current: Pubkey,
+    pub proposed: Pubkey,
+    pub kind: u8,
+}
+impl_event!(AuthorityProposed, 2);
+
+#[repr(C, packed)]
+pub struct AuthorityAccepted {
+    pub previous: Pubkey,
+    pub new: Pubkey,
+    pub kind: u8,
+}
+impl_event!(AuthorityAccepted, 3);
+
+#[repr(C, packed)]
+pub struct AuthorityCancelled {
+    pub authority: Pubkey,
+    pub cancelled: Pubkey,
+    pub kind: u8,
+}
+impl_event!(AuthorityCancelled, 4);
+
+#[repr(C, packed)]
+pub struct KycSubmitted {
+    pub creator: Pubkey,
+    pub creator_kyc: Pubkey,
+    pub commitment: [u8; 32],
+    pub provider: [u8; 16],
+}
+impl_event!(KycSubmitted, 5);
+
+#[repr(C, packed)]
+pub struct KycVerified {
+    pub creator: Pubkey,
+    pub kyc_authority: Pubkey,
+    pub decided_at: i64,
+    pub expires_at: i64,
+    pub status: u8,
+    pub reason_code: u8,
+}
+impl_event!(KycVerified, 6);
+
+#[repr(C, packed)]
+pub struct TokenConfigCreated {
+    pub token_config: Pubkey,
+    pub creator: Pubkey,
+    pub decimals: u8,
+}
+impl_event!(TokenConfigCreated, 7);
+
+#[repr(C, packed)]
+pub struct TokenConfigVerified {
+    pub token_config: Pubkey,
+    pub config_authority: Pubkey,
+    pub verified_at: i64,
+    pub status: u8,
+}
+impl_event!(TokenConfigVerified, 8);
+
+#[repr(C, packed)]
+pub struct RwaCreated {
+    pub token_config: Pubkey,
+    pub mint: Pubkey,
+    pub max_supply: u64,
+    pub decimals: u8,
+    pub extensions: u8,    // CreateRWA extension bitmask
+}
+impl_event!(RwaCreated, 9);
+
+#[repr(C, packed)]
+pub struct RwaMinted {
+    pub mint: Pubkey,
+    pub investor: Pubkey,
+    pub amount: u64,
+    pub supply: u64,       // supply after the mint
+}
+impl_event!(RwaMinted, 10);
+
+#[repr(C, packed)]
+pub struct Redeemed {
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub redemption: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub nonce: u64,
+}
+impl_event!(Redeemed, 11);
+
+#[repr(C, packed)]
+pub struct RedemptionSettled {
+    pub redemption: Pubkey,
+    pub settled_at: i64,
+    pub status: u8,
+    pub reason_code: u8,
+}
+impl_event!(RedemptionSettled, 12);
+
+/// What a `FeeCollected` fee was charged on
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FeeKind {
+    Issuance = 0,
+    Redemption = 1,
+}
+
+#[repr(C, packed)]
+pub struct FeeCollected {
+    pub mint: Pubkey,
+    pub fee_ata: Pubkey,
+    pub amount: u64,
+    pub kind: u8,          // FeeKind
+}
+impl_event!(FeeCollected, 13);
+
+#[repr(C, packed)]
+pub struct FeesWithdrawn {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub config_authority: Pubkey,
+    pub amount: u64,
+}
+impl_event!(FeesWithdrawn, 14);
+
+#[repr(C, packed)]
+pub struct TokenConfigFeesUpdated {
+    pub token_config: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub issuance_fee_bps: u16,
+    pub redemption_fee_bps: u16,
+    pub transfer_fee_bps: u16,
+}
+impl_event!(TokenConfigFeesUpdated, 15);
+
+#[repr(C, packed)]
+pub struct TransferFeesWithdrawn {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub harvested_accounts: u8,
+}
+impl_event!(TransferFeesWithdrawn, 16);
+
+#[repr(C, packed)]
+pub struct InvestorKycSubmitted {
+    pub investor: Pubkey,
+    pub investor_kyc: Pubkey,
+    pub jurisdiction: [u8; 2],
+    pub accreditation_tier: u8,
+}
+impl_event!(InvestorKycSubmitted, 17);
+
+#[repr(C, packed)]
+pub struct InvestorKycVerified {
+    pub investor: Pubkey,
+    pub kyc_authority: Pubkey,
+    pub decided_at: i64,
+    pub expires_at: i64,
+    pub jurisdiction: [u8; 2],
+    pub accreditation_tier: u8,
+    pub status: u8,
+    pub reason_code: u8,
+}
+impl_event!(InvestorKycVerified, 18);
+
+#[repr(C, packed)]
+pub struct TokenConfigRulesUpdated {
+    pub token_config: Pubkey,
+    pub jurisdiction_mode: u8,
+    pub jurisdiction_count: u8,
+    pub min_accreditation_tier: u8,
+}
+impl_event!(TokenConfigRulesUpdated, 19);
+
+#[repr(C, packed)]
+pub struct KycResubmitted {
+    pub creator: Pubkey,
+    pub creator_kyc: Pubkey,
+    pub commitment: [u8; 32],
+    pub provider: [u8; 16],
+    pub submission_count: u16,
+}
+impl_event!(KycResubmitted, 20);
+
+#[repr(C, packed)]
+pub struct CreatorKycClosed {
+    pub creator: Pubkey,
+    pub creator_kyc: Pubkey,
+    pub closed_by: Pubkey,
+    pub destination: Pubkey,
+}
+impl_event!(CreatorKycClosed, 21);
+
+#[repr(C, packed)]
+pub struct TokenConfigClosed {
+    pub token_config: Pubkey,
+    pub mint: Pubkey,
+    pub closed_by: Pubkey,
+    pub destination: Pubkey,
+}
+impl_event!(TokenConfigClosed, 22);
+
+#[repr(C, packed)]
+pub struct RwaMetadataUpdated {
+    pub mint: Pubkey,
+    pub update_authority: Pubkey,
+    pub field: u8, // MetadataUpdate
+    pub key: [u8; 32],
+}
+impl_event!(RwaMetadataUpdated, 23);
+
+#[repr(C, packed)]
+pub struct RwaMetadataFrozen {
+    pub mint: Pubkey,
+    pub update_authority: Pubkey,
+}
+impl_event!(RwaMetadataFrozen, 24);
+
+#[repr(C, packed)]
+pub struct InvestorAccountThawed {
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub token_account: Pubkey,
+}
+impl_event!(InvestorAccountThawed, 25);
+
+#[repr(C, packed)]
+pub struct HolderFrozen {
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub token_account: Pubkey,
+    pub authority: Pubkey,
+    pub reason_code: u8,
+}
+impl_event!(HolderFrozen, 26);
+
+#[repr(C, packed)]
+pub struct HolderThawed {
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub token_account: Pubkey,
+    pub authority: Pubkey,
+}
+impl_event!(HolderThawed, 27);
+
+#[repr(C, packed)]
+pub struct ForcedTransfer {
+    pub mint: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub reason_code: u8,
+}
+impl_event!(ForcedTransfer, 28);
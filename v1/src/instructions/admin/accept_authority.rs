@@ -0,0 +1,98 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{AuthorityAccepted, Event},
+        instructions::{RWAInstruction, AcceptAuthority, ZeroCopyTryFrom},
+        states::{AuthorityKind, GlobalConfig},
+        utils::{load_acc_mut_unchecked, AccountCheck, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to accept a pending authority
+pub struct AcceptAuthorityAccounts<'a> {
+    pub new_authority: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for AcceptAuthorityAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [new_authority, global_config] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(new_authority)?;
+        ProgramAccount::check(global_config)?;
+
+        Ok(Self {
+            new_authority,
+            global_config,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct AcceptAuthorityInstruction<'a> {
+    pub accounts: AcceptAuthorityAccounts<'a>,
+    pub instruction_datas: &'a AcceptAuthority,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for AcceptAuthorityInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = AcceptAuthorityAccounts::try_from(accounts)?;
+        let instruction_datas = AcceptAuthority::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> AcceptAuthorityInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::AcceptAuthority as u8;
+
+    /// Process the instruction: the proposed key proves it can sign and takes over
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        let (expected_pda, _) = find_program_address(&[GlobalConfig::SEED_PREFIX], program_id);
+        if *self.accounts.global_config.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let kind = AuthorityKind::try_from(self.instruction_datas.kind)?;
+
+        let mut global_config_data = self.accounts.global_config.try_borrow_mut_data()?;
+        let global_config: &mut GlobalConfig =
+            unsafe { load_acc_mut_unchecked(&mut global_config_data)? };
+
+        let pending = *global_config.pending_authority(kind);
+        if pending == Pubkey::default() {
+            return Err(RWAError::NoPendingAuthority.into());
+        }
+        if pending != *self.accounts.new_authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+
+        let previous = *global_config.authority(kind);
+        global_config.set_authority(kind, pending);
+        global_config.set_pending_authority(kind, Pubkey::default());
+
+        AuthorityAccepted {
+            previous,
+            new: pending,
+            kind: kind as u8,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
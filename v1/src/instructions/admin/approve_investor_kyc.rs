@@ -0,0 +1,117 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, InvestorKycVerified},
+        instructions::{ApproveInvestorKYC, RWAInstruction, ZeroCopyTryFrom},
+        states::{AccreditationTier, GlobalConfig, InvestorKYC, KycDecision},
+        utils::{load_acc_mut_unchecked, load_acc_unchecked, AccountCheck, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to approve an investor
+pub struct ApproveInvestorKYCAccounts<'a> {
+    pub kyc_authority: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+    pub investor_kyc: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ApproveInvestorKYCAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [kyc_authority, global_config, investor_kyc] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(kyc_authority)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(investor_kyc)?;
+
+        Ok(Self {
+            kyc_authority,
+            global_config,
+            investor_kyc,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct ApproveInvestorKYCInstruction<'a> {
+    pub accounts: ApproveInvestorKYCAccounts<'a>,
+    pub instruction_datas: &'a ApproveInvestorKYC,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for ApproveInvestorKYCInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = ApproveInvestorKYCAccounts::try_from(accounts)?;
+        let instruction_datas = ApproveInvestorKYC::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> ApproveInvestorKYCInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::ApproveInvestorKYC as u8;
+
+    /// Process the instruction: approve a Pending investor with the verified jurisdiction, tier and expiry
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        GlobalConfig::check_pda(self.accounts.global_config, program_id)?;
+
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+        if global_config.kyc_authority != *self.accounts.kyc_authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+
+        AccreditationTier::try_from(self.instruction_datas.accreditation_tier)?;
+        let now = Clock::get()?.unix_timestamp;
+        if self.instruction_datas.expires_at <= now {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        let mut investor_kyc_data = self.accounts.investor_kyc.try_borrow_mut_data()?;
+        let investor_kyc: &mut InvestorKYC = unsafe { load_acc_mut_unchecked(&mut investor_kyc_data)? };
+
+        let (expected_pda, _) = find_program_address(&[InvestorKYC::SEED_PREFIX, investor_kyc.wallet.as_ref()], program_id);
+        if *self.accounts.investor_kyc.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let status = KycDecision::Approve
+            .apply(investor_kyc.status()?)
+            .ok_or(RWAError::InvalidKycStatus)?;
+
+        investor_kyc.status = status as u8;
+        investor_kyc.reason_code = 0;
+        investor_kyc.jurisdiction = self.instruction_datas.jurisdiction;
+        investor_kyc.accreditation_tier = self.instruction_datas.accreditation_tier;
+        investor_kyc.expires_at = self.instruction_datas.expires_at;
+        investor_kyc.decided_at = now;
+
+        InvestorKycVerified {
+            investor: investor_kyc.wallet,
+            kyc_authority: *self.accounts.kyc_authority.key(),
+            decided_at: now,
+            expires_at: investor_kyc.expires_at,
+            jurisdiction: investor_kyc.jurisdiction,
+            accreditation_tier: investor_kyc.accreditation_tier,
+            status: investor_kyc.status,
+            reason_code: 0,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{AuthorityCancelled, Event},
+        instructions::{RWAInstruction, CancelAuthority, ZeroCopyTryFrom},
+        states::{AuthorityKind, GlobalConfig},
+        utils::{load_acc_mut_unchecked, AccountCheck, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to cancel a pending authority
+pub struct CancelAuthorityAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CancelAuthorityAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, global_config] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(authority)?;
+        ProgramAccount::check(global_config)?;
+
+        Ok(Self {
+            authority,
+            global_config,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct CancelAuthorityInstruction<'a> {
+    pub accounts: CancelAuthorityAccounts<'a>,
+    pub instruction_datas: &'a CancelAuthority,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for CancelAuthorityInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = CancelAuthorityAccounts::try_from(accounts)?;
+        let instruction_datas = CancelAuthority::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> CancelAuthorityInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::CancelAuthority as u8;
+
+    /// Process the instruction: the current authority withdraws its proposal
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        let (expected_pda, _) = find_program_address(&[GlobalConfig::SEED_PREFIX], program_id);
+        if *self.accounts.global_config.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let kind = AuthorityKind::try_from(self.instruction_datas.kind)?;
+
+        let mut global_config_data = self.accounts.global_config.try_borrow_mut_data()?;
+        let global_config: &mut GlobalConfig =
+            unsafe { load_acc_mut_unchecked(&mut global_config_data)? };
+
+        if global_config.authority(kind) != self.accounts.authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+        if *global_config.pending_authority(kind) == Pubkey::default() {
+            return Err(RWAError::NoPendingAuthority.into());
+        }
+
+        let cancelled = *global_config.pending_authority(kind);
+        global_config.set_pending_authority(kind, Pubkey::default());
+
+        AuthorityCancelled {
+            authority: *global_config.authority(kind),
+            cancelled,
+            kind: kind as u8,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        seeds,
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, ForcedTransfer},
+        instructions::{ForceTransfer, RWAInstruction, ZeroCopyTryFrom},
+        states::{GlobalConfig, TokenConfig},
+        utils::{
+            load_acc_unchecked, AccountCheck, Mint2022Account, ProgramAccount, SignerAccount, TokenAccount2022,
+            TransferCheckedWithHook, TransferHookAccounts, TOKEN_2022_PROGRAM_ID,
+        },
+    },
+};
+
+/// Accounts required to move a holder's tokens through the permanent delegate
+pub struct ForceTransferAccounts<'a> {
+    pub transfer_agent: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub source: &'a AccountInfo,
+    pub destination: &'a AccountInfo,     // any token account of `mint` whose owner is eligible
+    pub permanent_delegate: &'a AccountInfo,
+    pub freeze_authority: &'a AccountInfo, // thaws a source under legal hold for the move
+    pub token_program_2022: &'a AccountInfo,
+    // Forwarded to the KYC transfer hook
+    pub extra_account_metas: &'a AccountInfo,
+    pub source_kyc: &'a AccountInfo,
+    pub destination_kyc: &'a AccountInfo,
+    pub hook_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ForceTransferAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [transfer_agent, global_config, token_config, mint, source, destination, permanent_delegate, freeze_authority, token_program_2022, extra_account_metas, source_kyc, destination_kyc, hook_program] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(transfer_agent)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(token_config)?;
+        Mint2022Account::check(mint)?;
+        if *token_program_2022.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        Ok(Self {
+            transfer_agent,
+            global_config,
+            token_config,
+            mint,
+            source,
+            destination,
+            permanent_delegate,
+            freeze_authority,
+            token_program_2022,
+            extra_account_metas,
+            source_kyc,
+            destination_kyc,
+            hook_program,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct ForceTransferInstruction<'a> {
+    pub accounts: ForceTransferAccounts<'a>,
+    pub instruction_datas: &'a ForceTransfer,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for ForceTransferInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = ForceTransferAccounts::try_from(accounts)?;
+        let instruction_datas = ForceTransfer::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> ForceTransferInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::ForceTransfer as u8;
+
+    /// Process the instruction: move `amount` between two holders without the source owner's signature
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        let amount = self.instruction_datas.amount;
+        let reason_code = self.instruction_datas.reason_code;
+        if amount == 0 || reason_code == 0 {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+        if self.accounts.source.key() == self.accounts.destination.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        GlobalConfig::check_pda(self.accounts.global_config, program_id)?;
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+        global_config.check_transfer_agent(self.accounts.transfer_agent.key())?;
+
+        let token_config_data = self.accounts.token_config.try_borrow_data()?;
+        let token_config: &TokenConfig = unsafe { load_acc_unchecked(&token_config_data)? };
+        if token_config.mint == Pubkey::default() || token_config.mint != *self.accounts.mint.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+        if token_config.permanent_delegate == Pubkey::default() {
+            return Err(RWAError::PermanentDelegateNotSet.into());
+        }
+        if *self.accounts.permanent_delegate.key() != token_config.permanent_delegate {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let decimals = token_config.decimal;
+        let delegate_bump_ref = [token_config.permanent_delegate_bump];
+        let freeze_bump_ref = [token_config.freeze_authority_bump];
+        // The hook reads both configs again during the transfer
+        drop(global_config_data);
+        drop(token_config_data);
+
+        let delegate_seeds = seeds!(TokenConfig::PERMANENT_DELEGATE_SEED, self.accounts.token_config.key().as_ref(), &delegate_bump_ref);
+        let freeze_seeds = seeds!(TokenConfig::FREEZE_AUTHORITY_SEED, self.accounts.token_config.key().as_ref(), &freeze_bump_ref);
+
+        // Seizing from an account under legal hold is the usual case, the hold stays in place afterwards
+        let source_was_frozen = TokenAccount2022::is_frozen(self.accounts.source)?;
+        if source_was_frozen {
+            TokenAccount2022::thaw_if_frozen(
+                self.accounts.source,
+                self.accounts.mint,
+                self.accounts.freeze_authority,
+                &[Signer::from(&freeze_seeds)],
+            )?;
+        }
+
+        // The hook skips the source owner's KYC for the delegate, the destination must still be eligible
+        TransferCheckedWithHook {
+            from: self.accounts.source,
+            mint: self.accounts.mint,
+            to: self.accounts.destination,
+            authority: self.accounts.permanent_delegate,
+            amount,
+            decimals,
+            hook: TransferHookAccounts {
+                extra_account_metas: self.accounts.extra_account_metas,
+                global_config: self.accounts.global_config,
+                token_config: self.accounts.token_config,
+                sender_kyc: self.accounts.source_kyc,
+                receiver_kyc: self.accounts.destination_kyc,
+                hook_program: self.accounts.hook_program,
+            },
+        }
+        .invoke_signed(&[Signer::from(&delegate_seeds)])?;
+
+        if source_was_frozen {
+            TokenAccount2022::freeze_if_thawed(
+                self.accounts.source,
+                self.accounts.mint,
+                self.accounts.freeze_authority,
+                &[Signer::from(&freeze_seeds)],
+            )?;
+        }
+
+        ForcedTransfer {
+            mint: *self.accounts.mint.key(),
+            source: *self.accounts.source.key(),
+            destination: *self.accounts.destination.key(),
+            authority: *self.accounts.transfer_agent.key(),
+            amount,
+            reason_code,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        seeds,
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, HolderFrozen},
+        instructions::{FreezeHolder, RWAInstruction, ZeroCopyTryFrom},
+        states::{GlobalConfig, HolderStatus, TokenConfig},
+        utils::{
+            load_acc_mut_unchecked, load_acc_unchecked, AccountCheck, DataLen, ProgramAccount, ProgramAccountInit,
+            SignerAccount, TokenAccount2022, TOKEN_2022_PROGRAM_ID,
+        },
+    },
+};
+
+/// Accounts required to put a holder's position under a legal hold
+pub struct FreezeHolderAccounts<'a> {
+    pub authority: &'a AccountInfo,        // config or compliance authority, pays for the HolderStatus
+    pub global_config: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub token_account: &'a AccountInfo,
+    pub holder_status: &'a AccountInfo,
+    pub freeze_authority: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program_2022: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for FreezeHolderAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, global_config, token_config, mint, token_account, holder_status, freeze_authority, system_program, token_program_2022] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(authority)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(token_config)?;
+        if *token_program_2022.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        Ok(Self {
+            authority,
+            global_config,
+            token_config,
+            mint,
+            token_account,
+            holder_status,
+            freeze_authority,
+            system_program,
+            token_program_2022,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct FreezeHolderInstruction<'a> {
+    pub accounts: FreezeHolderAccounts<'a>,
+    pub instruction_datas: &'a FreezeHolder,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for FreezeHolderInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = FreezeHolderAccounts::try_from(accounts)?;
+        let instruction_datas = FreezeHolder::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> FreezeHolderInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::FreezeHolder as u8;
+
+    /// Process the instruction: freeze a token account and record the hold on its owner
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        let reason_code = self.instruction_datas.reason_code;
+        if reason_code == 0 {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        GlobalConfig::check_pda(self.accounts.global_config, program_id)?;
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+        global_config.check_compliance(self.accounts.authority.key())?;
+
+        let token_config_data = self.accounts.token_config.try_borrow_data()?;
+        let token_config: &TokenConfig = unsafe { load_acc_unchecked(&token_config_data)? };
+        if token_config.mint == Pubkey::default() || token_config.mint != *self.accounts.mint.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        // The hold follows the owner, so every account of theirs can be frozen under one record
+        let holder = TokenAccount2022::owner(self.accounts.token_account)?;
+        let (expected_pda, bump) = find_program_address(
+            &[HolderStatus::SEED_PREFIX, token_config.mint.as_ref(), holder.as_ref()],
+            program_id,
+        );
+        if *self.accounts.holder_status.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+        let bump_ref = [bump];
+        let seeds = seeds!(HolderStatus::SEED_PREFIX, token_config.mint.as_ref(), holder.as_ref(), &bump_ref);
+        ProgramAccount::init_if_needed(self.accounts.authority, self.accounts.holder_status, &seeds, HolderStatus::LEN)?;
+
+        let was_frozen = TokenAccount2022::is_frozen(self.accounts.token_account)?;
+        let freeze_bump_ref = [token_config.freeze_authority_bump];
+        let freeze_seeds = seeds!(TokenConfig::FREEZE_AUTHORITY_SEED, self.accounts.token_config.key().as_ref(), &freeze_bump_ref);
+        TokenAccount2022::freeze_if_thawed(
+            self.accounts.token_account,
+            self.accounts.mint,
+            self.accounts.freeze_authority,
+            &[Signer::from(&freeze_seeds)],
+        )?;
+
+        let mut holder_status_data = self.accounts.holder_status.try_borrow_mut_data()?;
+        let holder_status: &mut HolderStatus = unsafe { load_acc_mut_unchecked(&mut holder_status_data)? };
+
+        // Freezing another account of a holder already on hold only refreshes the reason.
+        // An account that was already frozen joins a new hold, under an existing one it was counted already.
+        if !holder_status.frozen {
+            holder_status.freeze_count = holder_status.freeze_count.saturating_add(1);
+            holder_status.frozen_accounts = 1;
+        } else if !was_frozen {
+            holder_status.frozen_accounts = holder_status.frozen_accounts.saturating_add(1);
+        }
+        holder_status.mint = token_config.mint;
+        holder_status.holder = holder;
+        holder_status.frozen_by = *self.accounts.authority.key();
+        holder_status.frozen_at = Clock::get()?.unix_timestamp;
+        holder_status.frozen = true;
+        holder_status.reason_code = reason_code;
+        holder_status.bump = bump;
+
+        HolderFrozen {
+            mint: holder_status.mint,
+            holder,
+            token_account: *self.accounts.token_account.key(),
+            authority: holder_status.frozen_by,
+            reason_code,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -2,45 +2,43 @@ use {
     core::convert::TryFrom,
     pinocchio::{
         account_info::AccountInfo,
-        instruction::{Signer},
         program_error::ProgramError,
-        pubkey::find_program_address,
+        pubkey::{find_program_address, Pubkey},
         seeds,
+        ProgramResult,
     },
     crate::{
-        instructions::{
-            RWAInstruction, InitGlobalConfig
-        }, // or `InitGlobalConfig` if you only need that variant
-        utils::{ProgramAccount, SignerAccount, load_acc_mut_unchecked},
         errors::RWAError,
+        events::{Event, GlobalConfigInitialized},
+        instructions::{InitGlobalConfig, RWAInstruction, ZeroCopyTryFrom},
+        states::{GlobalConfig, MAX_DECIMALS_POLICY, MAX_FEES_BPS},
+        utils::{load_acc_mut_unchecked, AccountCheck, DataLen, ProgramAccount, ProgramAccountInit, SignerAccount},
     },
 };
 
-
-/// Struct holding all relevant accounts for InitGlobalConfig
+/// Accounts required to initialize the GlobalConfig
 pub struct GlobalConfigAccounts<'a> {
-    pub config_authority: &'a AccountInfo,
+    pub config_authority: &'a AccountInfo,  // pays for the PDA
     pub kyc_authority: &'a AccountInfo,
     pub global_config: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for GlobalConfigAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let mut iter = infos.iter();
-        let [config_authority, kyc_authority, global_config]= infos else {
-                    return Err(RWAError::NotEnoughAccountKeys.into());
-        }
-        // Extract and validate accounts
-        let config_authority = SignerAccount::try_from(config_authority);
-        let kyc_authority = SignerAccount::try_from(kyc_authority);
-        let global_config =  ProgramAccount::try_from(global_config);
+        let [config_authority, kyc_authority, global_config, system_program] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(config_authority)?;
+        SignerAccount::check(kyc_authority)?;
 
         Ok(Self {
-            &config_authority,
-            &kyc_authority,
-            &global_config,
+            config_authority,
+            kyc_authority,
+            global_config,
+            system_program,
         })
     }
 }
@@ -48,7 +46,7 @@ impl<'a> TryFrom<&'a [AccountInfo]> for GlobalConfigAccounts<'a> {
 /// Instruction wrapper
 pub struct InitGlobalConfigInstruction<'a> {
     pub accounts: GlobalConfigAccounts<'a>,
-    pub instruction_datas: InitGlobalConfig,
+    pub instruction_datas: &'a InitGlobalConfig,
 }
 
 impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for InitGlobalConfigInstruction<'a> {
@@ -56,7 +54,7 @@ impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for InitGlobalConfigInstruction<
 
     fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
         let accounts = GlobalConfigAccounts::try_from(accounts)?;
-        let instruction_datas = InitGlobalConfig::try_from(data)?; // deserialize your instruction data
+        let instruction_datas = InitGlobalConfig::try_from_bytes(data)?;
 
         Ok(Self {
             accounts,
@@ -66,35 +64,51 @@ impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for InitGlobalConfigInstruction<
 }
 
 impl<'a> InitGlobalConfigInstruction<'a> {
-    pub const DISCRIMINATOR: u8 = 4;
-    
-    /// Process the instruction: initialize the GlobalConfig PDA
-     fn process(&mut self, program_id: &Pubkey) -> Result<(), ProgramError> {
-        // Derive the expected PDA
-        let (expected_pda, bump) = find_program_address(&[b"global-config"], program_id);
+    pub const DISCRIMINATOR: u8 = RWAInstruction::InitGlobalConfig as u8;
 
-        // Check PDA matches the account passed in
+    /// Process the instruction: create the GlobalConfig PDA, once
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        if self.instruction_datas.fees_bps > MAX_FEES_BPS {
+            return Err(RWAError::InvalidFeeBps.into());
+        }
+        if self.instruction_datas.max_decimal > MAX_DECIMALS_POLICY {
+            return Err(RWAError::InvalidDecimals.into());
+        }
+
+        let (expected_pda, bump) = find_program_address(&[GlobalConfig::SEED_PREFIX], program_id);
         if *self.accounts.global_config.key() != expected_pda {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RWAError::PdaMismatch.into());
         }
-        let seeds = seeds!("global-config", program_id);
-        let signer_seeds = Signer::from(&seeds);
+        let bump_ref = [bump];
+        let seeds = seeds!(GlobalConfig::SEED_PREFIX, &bump_ref);
+        // Fails once the config exists, authorities only change through propose/accept afterwards
+        ProgramAccount::init(self.accounts.config_authority, self.accounts.global_config, &seeds, GlobalConfig::LEN)?;
 
-        ProgramAccount::init_if_needed(self.accounts.signer, self.accounts.global_config, &signer_seeds, core::mem::size_of<GlobalConfig>);
-        // Borrow PDA data mutably
-        let global_config_data = &mut self.accounts.global_config.try_borrow_mut_data()?;
-        let global_config: &mut crate::states::GlobalConfig =
-            unsafe { load_acc_mut_unchecked(global_config_data)};
+        let mut global_config_data = self.accounts.global_config.try_borrow_mut_data()?;
+        let global_config: &mut GlobalConfig = unsafe { load_acc_mut_unchecked(&mut global_config_data)? };
 
-        // Write instruction data into the PDA
         global_config.config_authority = *self.accounts.config_authority.key();
         global_config.kyc_authority = *self.accounts.kyc_authority.key();
-        global_config.fees_bps = self.instruction_datas.fee_bps;
+        global_config.compliance_authority = global_config.config_authority;
+        global_config.pending_compliance_authority = Pubkey::default();
+        global_config.transfer_agent_authority = global_config.config_authority;
+        global_config.pending_transfer_agent_authority = Pubkey::default();
+        global_config.fees_bps = self.instruction_datas.fees_bps;
         global_config.max_decimal = self.instruction_datas.max_decimal;
         global_config.open_time = self.instruction_datas.open_time;
-        global_config.active = self.instruction_datas.active;
+        global_config.active = self.instruction_datas.active()?;
         global_config.bump = bump;
 
+        GlobalConfigInitialized {
+            config_authority: global_config.config_authority,
+            kyc_authority: global_config.kyc_authority,
+            open_time: global_config.open_time,
+            max_decimal: global_config.max_decimal,
+            fees_bps: global_config.fees_bps,
+            active: global_config.active,
+        }
+        .emit();
+
         Ok(())
     }
 }
@@ -9,3 +9,36 @@ pub use verify_creator_kyc::*;
 
 pub mod verify_token_config;
 pub use verify_token_config::*;
+
+pub mod propose_authority;
+pub use propose_authority::*;
+
+pub mod accept_authority;
+pub use accept_authority::*;
+
+pub mod cancel_authority;
+pub use cancel_authority::*;
+
+pub mod withdraw_fees;
+pub use withdraw_fees::*;
+
+pub mod set_token_config_fees;
+pub use set_token_config_fees::*;
+
+pub mod approve_investor_kyc;
+pub use approve_investor_kyc::*;
+
+pub mod revoke_investor_kyc;
+pub use revoke_investor_kyc::*;
+
+pub mod set_token_config_rules;
+pub use set_token_config_rules::*;
+
+pub mod freeze_holder;
+pub use freeze_holder::*;
+
+pub mod thaw_holder;
+pub use thaw_holder::*;
+
+pub mod force_transfer;
+pub use force_transfer::*;
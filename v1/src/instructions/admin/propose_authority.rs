@@ -0,0 +1,97 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{AuthorityProposed, Event},
+        instructions::{RWAInstruction, ProposeAuthority, ZeroCopyTryFrom},
+        states::{AuthorityKind, GlobalConfig},
+        utils::{load_acc_mut_unchecked, AccountCheck, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to propose a new authority
+pub struct ProposeAuthorityAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ProposeAuthorityAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, global_config] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(authority)?;
+        ProgramAccount::check(global_config)?;
+
+        Ok(Self {
+            authority,
+            global_config,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct ProposeAuthorityInstruction<'a> {
+    pub accounts: ProposeAuthorityAccounts<'a>,
+    pub instruction_datas: &'a ProposeAuthority,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for ProposeAuthorityInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = ProposeAuthorityAccounts::try_from(accounts)?;
+        let instruction_datas = ProposeAuthority::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> ProposeAuthorityInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::ProposeAuthority as u8;
+
+    /// Process the instruction: record the proposed key, the current authority stays in charge until it is accepted
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        let (expected_pda, _) = find_program_address(&[GlobalConfig::SEED_PREFIX], program_id);
+        if *self.accounts.global_config.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let kind = AuthorityKind::try_from(self.instruction_datas.kind)?;
+        let new_authority = self.instruction_datas.new_authority;
+
+        let mut global_config_data = self.accounts.global_config.try_borrow_mut_data()?;
+        let global_config: &mut GlobalConfig =
+            unsafe { load_acc_mut_unchecked(&mut global_config_data)? };
+
+        if global_config.authority(kind) != self.accounts.authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+        // The zero key is our "no pending authority" marker and proposing yourself is a no-op
+        if new_authority == Pubkey::default() || new_authority == *global_config.authority(kind) {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        global_config.set_pending_authority(kind, new_authority);
+
+        AuthorityProposed {
+            current: *global_config.authority(kind),
+            proposed: new_authority,
+            kind: kind as u8,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
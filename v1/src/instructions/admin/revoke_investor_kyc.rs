@@ -0,0 +1,115 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, InvestorKycVerified},
+        instructions::{RevokeInvestorKYC, RWAInstruction, ZeroCopyTryFrom},
+        states::{GlobalConfig, InvestorKYC, KycDecision},
+        utils::{load_acc_mut_unchecked, load_acc_unchecked, AccountCheck, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to revoke an investor
+pub struct RevokeInvestorKYCAccounts<'a> {
+    pub kyc_authority: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+    pub investor_kyc: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RevokeInvestorKYCAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [kyc_authority, global_config, investor_kyc] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(kyc_authority)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(investor_kyc)?;
+
+        Ok(Self {
+            kyc_authority,
+            global_config,
+            investor_kyc,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct RevokeInvestorKYCInstruction<'a> {
+    pub accounts: RevokeInvestorKYCAccounts<'a>,
+    pub instruction_datas: &'a RevokeInvestorKYC,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for RevokeInvestorKYCInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = RevokeInvestorKYCAccounts::try_from(accounts)?;
+        let instruction_datas = RevokeInvestorKYC::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> RevokeInvestorKYCInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::RevokeInvestorKYC as u8;
+
+    /// Process the instruction: revoke an Approved investor, or reject a Pending submission
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        GlobalConfig::check_pda(self.accounts.global_config, program_id)?;
+
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+        if global_config.kyc_authority != *self.accounts.kyc_authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+
+        let reason_code = self.instruction_datas.reason_code;
+        if reason_code == 0 {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        let mut investor_kyc_data = self.accounts.investor_kyc.try_borrow_mut_data()?;
+        let investor_kyc: &mut InvestorKYC = unsafe { load_acc_mut_unchecked(&mut investor_kyc_data)? };
+
+        let (expected_pda, _) = find_program_address(&[InvestorKYC::SEED_PREFIX, investor_kyc.wallet.as_ref()], program_id);
+        if *self.accounts.investor_kyc.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let from = investor_kyc.status()?;
+        let status = KycDecision::Revoke
+            .apply(from)
+            .or_else(|| KycDecision::Reject.apply(from))
+            .ok_or(RWAError::InvalidKycStatus)?;
+
+        investor_kyc.status = status as u8;
+        investor_kyc.reason_code = reason_code;
+        investor_kyc.decided_at = Clock::get()?.unix_timestamp;
+
+        InvestorKycVerified {
+            investor: investor_kyc.wallet,
+            kyc_authority: *self.accounts.kyc_authority.key(),
+            decided_at: investor_kyc.decided_at,
+            expires_at: investor_kyc.expires_at,
+            jurisdiction: investor_kyc.jurisdiction,
+            accreditation_tier: investor_kyc.accreditation_tier,
+            status: investor_kyc.status,
+            reason_code,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, TokenConfigFeesUpdated},
+        instructions::{RWAInstruction, SetTokenConfigFees, ZeroCopyTryFrom},
+        states::{GlobalConfig, TokenConfig, FEE_BPS_UNSET, MAX_FEES_BPS},
+        utils::{load_acc_mut_unchecked, load_acc_unchecked, AccountCheck, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to price an asset
+pub struct SetTokenConfigFeesAccounts<'a> {
+    pub config_authority: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetTokenConfigFeesAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [config_authority, global_config, token_config] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(config_authority)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(token_config)?;
+
+        Ok(Self {
+            config_authority,
+            global_config,
+            token_config,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct SetTokenConfigFeesInstruction<'a> {
+    pub accounts: SetTokenConfigFeesAccounts<'a>,
+    pub instruction_datas: &'a SetTokenConfigFees,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for SetTokenConfigFeesInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = SetTokenConfigFeesAccounts::try_from(accounts)?;
+        let instruction_datas = SetTokenConfigFees::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> SetTokenConfigFeesInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::SetTokenConfigFees as u8;
+
+    /// Process the instruction: overwrite the asset's fee overrides and fee recipient
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        GlobalConfig::check_pda(self.accounts.global_config, program_id)?;
+
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+        if global_config.config_authority != *self.accounts.config_authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+
+        let data = self.instruction_datas;
+        for fee_bps in [data.issuance_fee_bps, data.redemption_fee_bps, data.transfer_fee_bps] {
+            if fee_bps != FEE_BPS_UNSET && fee_bps > MAX_FEES_BPS {
+                return Err(RWAError::InvalidFeeBps.into());
+            }
+        }
+
+        let mut token_config_data = self.accounts.token_config.try_borrow_mut_data()?;
+        let token_config: &mut TokenConfig = unsafe { load_acc_mut_unchecked(&mut token_config_data)? };
+
+        // The TransferFeeConfig extension was written by CreateRWA, a new rate here would never reach it
+        if token_config.mint != Pubkey::default() && data.transfer_fee_bps != token_config.transfer_fee_bps {
+            return Err(RWAError::MintAlreadyCreated.into());
+        }
+
+        token_config.issuance_fee_bps = data.issuance_fee_bps;
+        token_config.redemption_fee_bps = data.redemption_fee_bps;
+        token_config.transfer_fee_bps = data.transfer_fee_bps;
+        token_config.fee_recipient = data.fee_recipient;
+
+        TokenConfigFeesUpdated {
+            token_config: *self.accounts.token_config.key(),
+            fee_recipient: token_config.fee_recipient,
+            issuance_fee_bps: token_config.issuance_fee_bps,
+            redemption_fee_bps: token_config.redemption_fee_bps,
+            transfer_fee_bps: token_config.transfer_fee_bps,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, TokenConfigRulesUpdated},
+        instructions::{RWAInstruction, SetTokenConfigRules, ZeroCopyTryFrom},
+        states::{AccreditationTier, GlobalConfig, JurisdictionMode, TokenConfig, MAX_JURISDICTIONS},
+        utils::{load_acc_mut_unchecked, load_acc_unchecked, AccountCheck, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to edit an asset's investor rules
+pub struct SetTokenConfigRulesAccounts<'a> {
+    pub config_authority: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetTokenConfigRulesAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [config_authority, global_config, token_config] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(config_authority)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(token_config)?;
+
+        Ok(Self {
+            config_authority,
+            global_config,
+            token_config,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct SetTokenConfigRulesInstruction<'a> {
+    pub accounts: SetTokenConfigRulesAccounts<'a>,
+    pub instruction_datas: &'a SetTokenConfigRules,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for SetTokenConfigRulesInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = SetTokenConfigRulesAccounts::try_from(accounts)?;
+        let instruction_datas = SetTokenConfigRules::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> SetTokenConfigRulesInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::SetTokenConfigRules as u8;
+
+    /// Process the instruction: replace the asset's jurisdiction list and minimum accreditation tier
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        GlobalConfig::check_pda(self.accounts.global_config, program_id)?;
+
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+        if global_config.config_authority != *self.accounts.config_authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+
+        let data = self.instruction_datas;
+        JurisdictionMode::try_from(data.jurisdiction_mode)?;
+        AccreditationTier::try_from(data.min_accreditation_tier)?;
+        if data.jurisdiction_count as usize > MAX_JURISDICTIONS {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        let mut token_config_data = self.accounts.token_config.try_borrow_mut_data()?;
+        let token_config: &mut TokenConfig = unsafe { load_acc_mut_unchecked(&mut token_config_data)? };
+
+        token_config.jurisdiction_mode = data.jurisdiction_mode;
+        token_config.jurisdiction_count = data.jurisdiction_count;
+        // Unused slots are cleared so a shorter list can't leave stale codes behind
+        token_config.jurisdictions = [0; 2 * MAX_JURISDICTIONS];
+        let len = 2 * data.jurisdiction_count as usize;
+        token_config.jurisdictions[..len].copy_from_slice(&data.jurisdictions[..len]);
+        token_config.min_accreditation_tier = data.min_accreditation_tier;
+
+        TokenConfigRulesUpdated {
+            token_config: *self.accounts.token_config.key(),
+            jurisdiction_mode: token_config.jurisdiction_mode,
+            jurisdiction_count: token_config.jurisdiction_count,
+            min_accreditation_tier: token_config.min_accreditation_tier,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
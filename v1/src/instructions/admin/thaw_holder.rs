@@ -0,0 +1,140 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        seeds,
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, HolderThawed},
+        instructions::RWAInstruction,
+        states::{GlobalConfig, HolderStatus, TokenConfig},
+        utils::{
+            load_acc_mut_unchecked, load_acc_unchecked, AccountCheck, ProgramAccount, SignerAccount,
+            TokenAccount2022, TOKEN_2022_PROGRAM_ID,
+        },
+    },
+};
+
+/// Accounts required to lift a legal hold
+pub struct ThawHolderAccounts<'a> {
+    pub authority: &'a AccountInfo,        // config or compliance authority
+    pub global_config: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub token_account: &'a AccountInfo,
+    pub holder_status: &'a AccountInfo,
+    pub freeze_authority: &'a AccountInfo,
+    pub token_program_2022: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ThawHolderAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, global_config, token_config, mint, token_account, holder_status, freeze_authority, token_program_2022] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(authority)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(token_config)?;
+        ProgramAccount::check(holder_status)?;
+        if *token_program_2022.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        Ok(Self {
+            authority,
+            global_config,
+            token_config,
+            mint,
+            token_account,
+            holder_status,
+            freeze_authority,
+            token_program_2022,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct ThawHolderInstruction<'a> {
+    pub accounts: ThawHolderAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for ThawHolderInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+        let accounts = ThawHolderAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> ThawHolderInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::ThawHolder as u8;
+
+    /// Process the instruction: thaw a token account of a holder, lifting their hold with the last one
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        GlobalConfig::check_pda(self.accounts.global_config, program_id)?;
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+        global_config.check_compliance(self.accounts.authority.key())?;
+
+        let token_config_data = self.accounts.token_config.try_borrow_data()?;
+        let token_config: &TokenConfig = unsafe { load_acc_unchecked(&token_config_data)? };
+        if token_config.mint == Pubkey::default() || token_config.mint != *self.accounts.mint.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        let holder = TokenAccount2022::owner(self.accounts.token_account)?;
+        let (expected_pda, _) = find_program_address(
+            &[HolderStatus::SEED_PREFIX, token_config.mint.as_ref(), holder.as_ref()],
+            program_id,
+        );
+        if *self.accounts.holder_status.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        // Still callable once the hold is lifted, to thaw the holder's remaining accounts
+        let was_frozen = TokenAccount2022::is_frozen(self.accounts.token_account)?;
+        let freeze_bump_ref = [token_config.freeze_authority_bump];
+        let freeze_seeds = seeds!(TokenConfig::FREEZE_AUTHORITY_SEED, self.accounts.token_config.key().as_ref(), &freeze_bump_ref);
+        TokenAccount2022::thaw_if_frozen(
+            self.accounts.token_account,
+            self.accounts.mint,
+            self.accounts.freeze_authority,
+            &[Signer::from(&freeze_seeds)],
+        )?;
+
+        let mut holder_status_data = self.accounts.holder_status.try_borrow_mut_data()?;
+        let holder_status: &mut HolderStatus = unsafe { load_acc_mut_unchecked(&mut holder_status_data)? };
+        // ThawInvestorAccount refuses while `frozen` is set, so the hold stays until every held account is thawed here
+        if holder_status.frozen && was_frozen {
+            holder_status.frozen_accounts = holder_status.frozen_accounts.saturating_sub(1);
+            if holder_status.frozen_accounts == 0 {
+                holder_status.frozen = false;
+                holder_status.reason_code = 0;
+                holder_status.thawed_at = Clock::get()?.unix_timestamp;
+            }
+        }
+
+        HolderThawed {
+            mint: token_config.mint,
+            holder,
+            token_account: *self.accounts.token_account.key(),
+            authority: *self.accounts.authority.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, GlobalConfigUpdated},
+        instructions::{RWAInstruction, UpdateGlobalConfig, ZeroCopyTryFrom},
+        states::{GlobalConfig, MAX_FEES_BPS, MAX_DECIMALS_POLICY},
+        utils::{load_acc_mut_unchecked, AccountCheck, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to update the GlobalConfig
+pub struct UpdateGlobalConfigAccounts<'a> {
+    pub config_authority: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for UpdateGlobalConfigAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [config_authority, global_config] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(config_authority)?;
+        ProgramAccount::check(global_config)?;
+
+        Ok(Self {
+            config_authority,
+            global_config,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct UpdateGlobalConfigInstruction<'a> {
+    pub accounts: UpdateGlobalConfigAccounts<'a>,
+    pub instruction_datas: &'a UpdateGlobalConfig,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for UpdateGlobalConfigInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = UpdateGlobalConfigAccounts::try_from(accounts)?;
+        let instruction_datas = UpdateGlobalConfig::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> UpdateGlobalConfigInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::UpdateGlobalConfig as u8;
+
+    /// Process the instruction: overwrite only the GlobalConfig fields selected by `mask`
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        let (expected_pda, _) = find_program_address(&[GlobalConfig::SEED_PREFIX], program_id);
+        if *self.accounts.global_config.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let mask = self.instruction_datas.mask;
+        // Nothing to update, or a bit we don't know about: refuse rather than guess.
+        if mask == 0 || mask & !UpdateGlobalConfig::ALL != 0 {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        let mut global_config_data = self.accounts.global_config.try_borrow_mut_data()?;
+        let global_config: &mut GlobalConfig =
+            unsafe { load_acc_mut_unchecked(&mut global_config_data)? };
+
+        if global_config.config_authority != *self.accounts.config_authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+
+        if mask & UpdateGlobalConfig::FEES_BPS != 0
+            && self.instruction_datas.fees_bps > MAX_FEES_BPS
+        {
+            return Err(RWAError::InvalidFeeBps.into());
+        }
+        if mask & UpdateGlobalConfig::MAX_DECIMAL != 0
+            && self.instruction_datas.max_decimal > MAX_DECIMALS_POLICY
+        {
+            return Err(RWAError::InvalidDecimals.into());
+        }
+
+        if mask & UpdateGlobalConfig::ACTIVE != 0 {
+            global_config.active = self.instruction_datas.active()?;
+        }
+        if mask & UpdateGlobalConfig::OPEN_TIME != 0 {
+            global_config.open_time = self.instruction_datas.open_time;
+        }
+        if mask & UpdateGlobalConfig::MAX_DECIMAL != 0 {
+            global_config.max_decimal = self.instruction_datas.max_decimal;
+        }
+        if mask & UpdateGlobalConfig::FEES_BPS != 0 {
+            global_config.fees_bps = self.instruction_datas.fees_bps;
+        }
+
+        GlobalConfigUpdated {
+            config_authority: global_config.config_authority,
+            open_time: global_config.open_time,
+            mask,
+            max_decimal: global_config.max_decimal,
+            fees_bps: global_config.fees_bps,
+            active: global_config.active,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, KycVerified},
+        instructions::{RWAInstruction, VerifyCreatorKYC, ZeroCopyTryFrom},
+        states::{CreatorKYC, GlobalConfig, KycDecision},
+        utils::{load_acc_mut_unchecked, load_acc_unchecked, AccountCheck, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to verify a creator's KYC
+pub struct VerifyCreatorKYCAccounts<'a> {
+    pub kyc_authority: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+    pub creator_kyc: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for VerifyCreatorKYCAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [kyc_authority, global_config, creator_kyc] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(kyc_authority)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(creator_kyc)?;
+
+        Ok(Self {
+            kyc_authority,
+            global_config,
+            creator_kyc,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct VerifyCreatorKYCInstruction<'a> {
+    pub accounts: VerifyCreatorKYCAccounts<'a>,
+    pub instruction_datas: &'a VerifyCreatorKYC,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for VerifyCreatorKYCInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = VerifyCreatorKYCAccounts::try_from(accounts)?;
+        let instruction_datas = VerifyCreatorKYC::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> VerifyCreatorKYCInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::VerifyCreatorKYC as u8;
+
+    /// Process the instruction: approve (until `expires_at`), reject or revoke a creator's KYC record
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        let (expected_pda, _) = find_program_address(&[GlobalConfig::SEED_PREFIX], program_id);
+        if *self.accounts.global_config.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+        if global_config.kyc_authority != *self.accounts.kyc_authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+
+        let decision = KycDecision::try_from(self.instruction_datas.decision)?;
+        // A rejection or revocation must say why, an approval has nothing to explain
+        let reason_code = self.instruction_datas.reason_code;
+        if (decision == KycDecision::Approve) != (reason_code == 0) {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+        let now = Clock::get()?.unix_timestamp;
+        let expires_at = self.instruction_datas.expires_at;
+        let valid_expiry = if decision == KycDecision::Approve { expires_at > now } else { expires_at == 0 };
+        if !valid_expiry {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        let mut creator_kyc_data = self.accounts.creator_kyc.try_borrow_mut_data()?;
+        let creator_kyc: &mut CreatorKYC = unsafe { load_acc_mut_unchecked(&mut creator_kyc_data)? };
+
+        let (expected_pda, _) = find_program_address(&[CreatorKYC::SEED_PREFIX, creator_kyc.wallet.as_ref()], program_id);
+        if *self.accounts.creator_kyc.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let status = decision
+            .apply(creator_kyc.status()?)
+            .ok_or(RWAError::InvalidKycStatus)?;
+
+        creator_kyc.status = status as u8;
+        creator_kyc.reason_code = reason_code;
+        creator_kyc.decided_at = now;
+        if decision == KycDecision::Approve {
+            creator_kyc.expires_at = expires_at;
+            creator_kyc.approval_count = creator_kyc.approval_count.saturating_add(1);
+        }
+
+        KycVerified {
+            creator: creator_kyc.wallet,
+            kyc_authority: *self.accounts.kyc_authority.key(),
+            decided_at: creator_kyc.decided_at,
+            expires_at: creator_kyc.expires_at,
+            status: creator_kyc.status,
+            reason_code,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, TokenConfigVerified},
+        instructions::{RWAInstruction, VerifyTokenConfig, ZeroCopyTryFrom},
+        states::{GlobalConfig, TokenConfig, TokenConfigAction, TokenConfigStatus},
+        utils::{load_acc_mut_unchecked, load_acc_unchecked, AccountCheck, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to review a token config
+pub struct VerifyTokenConfigAccounts<'a> {
+    pub config_authority: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for VerifyTokenConfigAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [config_authority, global_config, token_config] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(config_authority)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(token_config)?;
+
+        Ok(Self {
+            config_authority,
+            global_config,
+            token_config,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct VerifyTokenConfigInstruction<'a> {
+    pub accounts: VerifyTokenConfigAccounts<'a>,
+    pub instruction_datas: &'a VerifyTokenConfig,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for VerifyTokenConfigInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = VerifyTokenConfigAccounts::try_from(accounts)?;
+        let instruction_datas = VerifyTokenConfig::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> VerifyTokenConfigInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::VerifyTokenConfig as u8;
+
+    /// Process the instruction: activate, suspend or retire a token config
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        let (expected_pda, _) = find_program_address(&[GlobalConfig::SEED_PREFIX], program_id);
+        if *self.accounts.global_config.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+        if global_config.config_authority != *self.accounts.config_authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+
+        let action = TokenConfigAction::try_from(self.instruction_datas.action)?;
+
+        // Owner + exact length is enough to know this is one of our TokenConfig PDAs
+        let mut token_config_data = self.accounts.token_config.try_borrow_mut_data()?;
+        let token_config: &mut TokenConfig = unsafe { load_acc_mut_unchecked(&mut token_config_data)? };
+
+        let status = action
+            .apply(token_config.status()?)
+            .ok_or(RWAError::InvalidTokenConfigStatus)?;
+
+        token_config.status = status as u8;
+        token_config.active = status == TokenConfigStatus::Active;
+        token_config.verified_by = *self.accounts.config_authority.key();
+        token_config.verified_at = Clock::get()?.unix_timestamp;
+
+        TokenConfigVerified {
+            token_config: *self.accounts.token_config.key(),
+            config_authority: token_config.verified_by,
+            verified_at: token_config.verified_at,
+            status: token_config.status,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
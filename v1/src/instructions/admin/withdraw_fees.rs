@@ -0,0 +1,152 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        seeds,
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, FeesWithdrawn},
+        instructions::{RWAInstruction, WithdrawFees, ZeroCopyTryFrom},
+        states::{GlobalConfig, FEE_VAULT_SEED},
+        utils::{
+            load_acc_unchecked, AccountCheck, AssociatedTokenAccount, AssociatedTokenAccountCheck,
+            Mint2022Account, ProgramAccount, SignerAccount, TransferCheckedWithHook, TransferHookAccounts,
+            TOKEN_2022_PROGRAM_ID,
+        },
+    },
+};
+
+/// Accounts required to withdraw collected protocol fees
+pub struct WithdrawFeesAccounts<'a> {
+    pub config_authority: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub fee_vault: &'a AccountInfo,
+    pub fee_vault_ata: &'a AccountInfo,
+    pub destination: &'a AccountInfo,     // any token account of `mint`
+    pub token_program_2022: &'a AccountInfo,
+    // Forwarded to the KYC transfer hook
+    pub extra_account_metas: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+    pub fee_vault_kyc: &'a AccountInfo,
+    pub destination_kyc: &'a AccountInfo,
+    pub hook_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for WithdrawFeesAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [config_authority, global_config, mint, fee_vault, fee_vault_ata, destination, token_program_2022, extra_account_metas, token_config, fee_vault_kyc, destination_kyc, hook_program] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(config_authority)?;
+        ProgramAccount::check(global_config)?;
+        Mint2022Account::check(mint)?;
+        if *token_program_2022.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        Ok(Self {
+            config_authority,
+            global_config,
+            mint,
+            fee_vault,
+            fee_vault_ata,
+            destination,
+            token_program_2022,
+            extra_account_metas,
+            token_config,
+            fee_vault_kyc,
+            destination_kyc,
+            hook_program,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct WithdrawFeesInstruction<'a> {
+    pub accounts: WithdrawFeesAccounts<'a>,
+    pub instruction_datas: &'a WithdrawFees,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for WithdrawFeesInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = WithdrawFeesAccounts::try_from(accounts)?;
+        let instruction_datas = WithdrawFees::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> WithdrawFeesInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::WithdrawFees as u8;
+
+    /// Process the instruction: move `amount` of one mint's collected fees out of the fee vault
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        let amount = self.instruction_datas.amount;
+        if amount == 0 {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        GlobalConfig::check_pda(self.accounts.global_config, program_id)?;
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+
+        if global_config.config_authority != *self.accounts.config_authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+        // The hook reads the config again during the transfer
+        drop(global_config_data);
+
+        let bump = GlobalConfig::fee_vault_bump(self.accounts.fee_vault, program_id)?;
+        AssociatedTokenAccount::check(
+            self.accounts.fee_vault_ata,
+            self.accounts.fee_vault,
+            self.accounts.mint,
+            self.accounts.token_program_2022,
+        )?;
+
+        let bump_ref = [bump];
+        let seeds = seeds!(FEE_VAULT_SEED, &bump_ref);
+
+        // The hook checks the destination owner's KYC, the vault itself is exempt
+        TransferCheckedWithHook {
+            from: self.accounts.fee_vault_ata,
+            mint: self.accounts.mint,
+            to: self.accounts.destination,
+            authority: self.accounts.fee_vault,
+            amount,
+            decimals: Mint2022Account::decimals(self.accounts.mint)?,
+            hook: TransferHookAccounts {
+                extra_account_metas: self.accounts.extra_account_metas,
+                global_config: self.accounts.global_config,
+                token_config: self.accounts.token_config,
+                sender_kyc: self.accounts.fee_vault_kyc,
+                receiver_kyc: self.accounts.destination_kyc,
+                hook_program: self.accounts.hook_program,
+            },
+        }
+        .invoke_signed(&[Signer::from(&seeds)])?;
+
+        FeesWithdrawn {
+            mint: *self.accounts.mint.key(),
+            destination: *self.accounts.destination.key(),
+            config_authority: *self.accounts.config_authority.key(),
+            amount,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use {
+    pinocchio::ProgramResult,
+    crate::{errors::RWAError, instructions::RWAInstruction},
+};
+
+/// Splits the data of a Batch instruction into records and hands each one to `dispatch`,
+/// stopping at the first error.
+///
+/// Layout, repeated until the data is exhausted:
+/// `[discriminator: u8][account count: u8][data len: u16 LE][data: data len bytes]`
+/// Each record consumes the next `account count` accounts, in order, from `accounts`.
+/// Generic over the account type so the parsing can be exercised off-chain.
+pub fn process_batch<A, F>(mut accounts: &[A], mut data: &[u8], mut dispatch: F) -> ProgramResult
+where
+    F: FnMut(RWAInstruction, &[A], &[u8]) -> ProgramResult,
+{
+    if data.is_empty() {
+        return Err(RWAError::InvalidInstructionData.into());
+    }
+
+    while !data.is_empty() {
+        let [ix_disc, account_count, len_lo, len_hi, tail @ ..] = data else {
+            return Err(RWAError::InvalidInstructionData.into());
+        };
+        let ix = RWAInstruction::try_from(*ix_disc)?;
+        // Batches don't nest
+        if ix == RWAInstruction::Batch {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        let data_len = u16::from_le_bytes([*len_lo, *len_hi]) as usize;
+        if tail.len() < data_len {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+        let (ix_data, next_data) = tail.split_at(data_len);
+
+        let account_count = *account_count as usize;
+        if accounts.len() < account_count {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        }
+        let (ix_accounts, next_accounts) = accounts.split_at(account_count);
+
+        dispatch(ix, ix_accounts, ix_data)?;
+
+        accounts = next_accounts;
+        data = next_data;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{CreatorKycClosed, Event},
+        instructions::{InitTokenConfigInstruction, RWAInstruction},
+        states::{CreatorKYC, GlobalConfig, KycStatus, TokenConfig, TokenConfigStatus},
+        utils::{load_acc_unchecked, AccountCheck, AccountClose, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to close a creator KYC record
+pub struct CloseCreatorKYCAccounts<'a> {
+    pub authority: &'a AccountInfo,     // the creator or the kyc_authority
+    pub global_config: &'a AccountInfo,
+    pub creator_kyc: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,  // the creator's token config PDA, may not exist
+    pub destination: &'a AccountInfo,   // receives the rent
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CloseCreatorKYCAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, global_config, creator_kyc, token_config, destination] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(authority)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(creator_kyc)?;
+
+        Ok(Self { authority, global_config, creator_kyc, token_config, destination })
+    }
+}
+
+/// Instruction wrapper
+pub struct CloseCreatorKYCInstruction<'a> {
+    pub accounts: CloseCreatorKYCAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for CloseCreatorKYCInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((_data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = CloseCreatorKYCAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> CloseCreatorKYCInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::CloseCreatorKYC as u8;
+
+    /// Process the instruction: refund a creator KYC record that no live token config depends on
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        GlobalConfig::check_pda(self.accounts.global_config, program_id)?;
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+
+        let creator_kyc_data = self.accounts.creator_kyc.try_borrow_data()?;
+        let creator_kyc: &CreatorKYC = unsafe { load_acc_unchecked(&creator_kyc_data)? };
+        let creator = creator_kyc.wallet;
+
+        let (expected_pda, _) = find_program_address(&[CreatorKYC::SEED_PREFIX, creator.as_ref()], program_id);
+        if *self.accounts.creator_kyc.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let authority = *self.accounts.authority.key();
+        if authority != creator && authority != global_config.kyc_authority {
+            return Err(RWAError::Unauthorized.into());
+        }
+        // Closing wipes the history, a creator could shed a rejection and come back fresh at Pending
+        if authority != global_config.kyc_authority {
+            let now = Clock::get()?.unix_timestamp;
+            match creator_kyc.effective_status(now)? {
+                KycStatus::Approved | KycStatus::Expired => {}
+                _ => return Err(RWAError::Unauthorized.into()),
+            }
+        }
+
+        let (expected_pda, _) = find_program_address(
+            &[InitTokenConfigInstruction::SEED_PREFIX, self.accounts.creator_kyc.key().as_ref()],
+            program_id,
+        );
+        if *self.accounts.token_config.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+        // A Pending or Suspended config can still go live, only a Retired or closed one lets go of its creator
+        if self.accounts.token_config.is_owned_by(program_id) {
+            let token_config_data = self.accounts.token_config.try_borrow_data()?;
+            let token_config: &TokenConfig = unsafe { load_acc_unchecked(&token_config_data)? };
+            if token_config.status()? != TokenConfigStatus::Retired {
+                return Err(RWAError::AccountInUse.into());
+            }
+        }
+
+        drop(creator_kyc_data);
+        ProgramAccount::close(self.accounts.creator_kyc, self.accounts.destination)?;
+
+        CreatorKycClosed {
+            creator,
+            creator_kyc: *self.accounts.creator_kyc.key(),
+            closed_by: authority,
+            destination: *self.accounts.destination.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, TokenConfigClosed},
+        instructions::RWAInstruction,
+        states::{GlobalConfig, TokenConfig},
+        utils::{load_acc_unchecked, AccountCheck, AccountClose, Mint2022Account, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to close a token config
+pub struct CloseTokenConfigAccounts<'a> {
+    pub authority: &'a AccountInfo,     // the creator or the config_authority
+    pub global_config: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,          // token_config.mint, the system program if CreateRWA never ran
+    pub destination: &'a AccountInfo,   // receives the rent
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CloseTokenConfigAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, global_config, token_config, mint, destination] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(authority)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(token_config)?;
+
+        Ok(Self { authority, global_config, token_config, mint, destination })
+    }
+}
+
+/// Instruction wrapper
+pub struct CloseTokenConfigInstruction<'a> {
+    pub accounts: CloseTokenConfigAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for CloseTokenConfigInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((_data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = CloseTokenConfigAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> CloseTokenConfigInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::CloseTokenConfig as u8;
+
+    /// Process the instruction: refund a token config whose mint has nothing outstanding
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        GlobalConfig::check_pda(self.accounts.global_config, program_id)?;
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+
+        let token_config_data = self.accounts.token_config.try_borrow_data()?;
+        let token_config: &TokenConfig = unsafe { load_acc_unchecked(&token_config_data)? };
+
+        let authority = *self.accounts.authority.key();
+        if authority != token_config.creator && authority != global_config.config_authority {
+            return Err(RWAError::Unauthorized.into());
+        }
+
+        let mint = token_config.mint;
+        if *self.accounts.mint.key() != mint {
+            return Err(RWAError::InvalidAddress.into());
+        }
+        if mint != Pubkey::default() {
+            Mint2022Account::check(self.accounts.mint)?;
+            if Mint2022Account::supply(self.accounts.mint)? != 0 {
+                return Err(RWAError::SupplyNotZero.into());
+            }
+        }
+        // Burned tokens of an open redemption can still be minted back on reject
+        if token_config.pending_redemptions != 0 {
+            return Err(RWAError::AccountInUse.into());
+        }
+
+        drop(token_config_data);
+        ProgramAccount::close(self.accounts.token_config, self.accounts.destination)?;
+
+        TokenConfigClosed {
+            token_config: *self.accounts.token_config.key(),
+            mint,
+            closed_by: authority,
+            destination: *self.accounts.destination.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -1,14 +1,23 @@
 use {
+    core::convert::TryFrom,
     pinocchio::{
         account_info::AccountInfo,
+        instruction::Signer,
         program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        seeds,
+        sysvars::{clock::Clock, Sysvar},
         ProgramResult,
     },
-    pinocchio_log,
     crate::{
-        instructions::CreateRWA,
+        events::{Event, RwaCreated},
+        instructions::{CreateRWA, InitTokenConfigInstruction, RWAInstruction, ZeroCopyTryFrom},
+        states::{CreatorKYC, GlobalConfig, TokenConfig},
         utils::{
-            ProgramAccount, SignerAccount, SystemAccount,Mint2022Account, AccountCheck
+            fund_rent_exemption, load_acc_mut_unchecked, load_acc_unchecked, trim_nul, AccountCheck,
+            ExtraAccountMetas, InitializeTokenMetadata, MetadataField, MetadataPointerInit, Mint2022Account,
+            MintExtensions, MintInit, ProgramAccount, SignerAccount, SystemAccount, TransferFeeInit,
+            TransferHookInit, UpdateTokenMetadataField, ASSET_TYPE_KEY, AUDIT_CID_KEY, TOKEN_2022_PROGRAM_ID,
         },
         errors::RWAError,
     },
@@ -16,54 +25,220 @@ use {
 
 pub struct CreateRWAAccount<'a> {
     pub signer: &'a AccountInfo,
-    pub mint_account: &'a AccountInfo, //mint account
-    pub metadata_account: &'a AccountInfo, //metadata account to store the additional info for the mint
-    pub mint_authority: &'a AccountInfo,
-    pub freeze_authority: &'a AccountInfo,
-    pub token_program_2022: &'a Option<AccountInfo>,
+    pub global_config: &'a AccountInfo,
+    pub creator_kyc: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+    pub mint_account: &'a AccountInfo, //mint account, a fresh keypair that signs the tx
+    pub extra_account_metas: &'a AccountInfo, //transfer hook validation PDA of the mint
+    pub mint_authority: &'a AccountInfo, //program PDA derived from the token config
+    pub freeze_authority: &'a AccountInfo, //program PDA derived from the token config
+    pub system_program: &'a AccountInfo,
+    pub token_program_2022: &'a AccountInfo,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for CreateRWAAccount<'a> {
     type Error = ProgramError;
 
     fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [signer, mint_account, metadata_account, mint_authority, freeze_authority, token_program_2022] = infos else {
-            return Err(RWAError:NotEnoughAccountKeys.into())
+        let [signer, global_config, creator_kyc, token_config, mint_account, extra_account_metas, mint_authority, freeze_authority, system_program, token_program_2022] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into())
         };
-        let signer = SignerAccount::try_from(&signer)?;
-        let mint_account = Mint2022Account::check(&mint_account)?;
-        let metadata_account  = ProgramAccount::try_from(&metadata_account)?;
-        let mint_authority = SystemAccount::try_from(&mint_authority);
-        let freeze_authority =  SystemAccount::try_from(&freeze_authority);
-        let token_program_2022 = SystemAccount::try_from(&token_program_2022)?;
-        
-        Ok(Self { signer, mint_account, metadata_account, mint_authority, freeze_authority, token_program_2022 });
+        SignerAccount::check(signer)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(creator_kyc)?;
+        ProgramAccount::check(token_config)?;
+        // Not created yet, so still owned by the system program
+        SignerAccount::check(mint_account)?;
+        SystemAccount::check(mint_account)?;
+        if *token_program_2022.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        Ok(Self { signer, global_config, creator_kyc, token_config, mint_account, extra_account_metas, mint_authority, freeze_authority, system_program, token_program_2022 })
     }
 }
 
-    pub struct CreateRWAInstruction<'a> {
-        pub accounts: CreateRWAAccount<'a>,
-        pub instruction_datas: CreateRWA,
+pub struct CreateRWAInstruction<'a> {
+    pub accounts: CreateRWAAccount<'a>,
+    pub instruction_datas: &'a CreateRWA,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for CreateRWAInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = CreateRWAAccount::try_from(accounts)?;
+        let instruction_datas = CreateRWA::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
     }
+}
+
+impl<'a> CreateRWAInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::CreateRWA as u8;
+
+    /// Process the instruction: create the Token-2022 mint, with its metadata on the mint itself, for an approved creator's active token config
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        GlobalConfig::check_open_account(self.accounts.global_config, program_id)?;
+
+        let creator = self.accounts.signer.key();
 
-    impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for CreateRWAInstruction<'a> {
-        type Error = ProgramError;
-    
-        fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
-            let accounts = CreateRWAAccount::try_from(accounts);
-            let instruction_datas = CreateRWA::try_from(data);
-    
-            Ok(Self {
-                accounts,
-                instruction_datas,
-            })
+        // Only a creator whose KYC went through can issue
+        let creator_kyc_data = self.accounts.creator_kyc.try_borrow_data()?;
+        let creator_kyc: &CreatorKYC = unsafe { load_acc_unchecked(&creator_kyc_data)? };
+        let (expected_pda, _) = find_program_address(&[CreatorKYC::SEED_PREFIX, creator.as_ref()], program_id);
+        if *self.accounts.creator_kyc.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
         }
-    }
-    //need to add lifetime
-    impl CreateRWAInstruction {
-        pub const DISCRIMINATOR: usize = 1;
-        pub fn process() -> ProgramResult<> {
+        creator_kyc.check_approved(Clock::get()?.unix_timestamp)?;
 
+        // The token config is bound to the creator through its KYC PDA
+        let (expected_pda, _) = find_program_address(
+            &[InitTokenConfigInstruction::SEED_PREFIX, self.accounts.creator_kyc.key().as_ref()],
+            program_id,
+        );
+        if *self.accounts.token_config.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
         }
-    }
 
+        let mut token_config_data = self.accounts.token_config.try_borrow_mut_data()?;
+        let token_config: &mut TokenConfig = unsafe { load_acc_mut_unchecked(&mut token_config_data)? };
+
+        if !token_config.active {
+            return Err(RWAError::TokenConfigNotActive.into());
+        }
+        if token_config.mint != Pubkey::default() {
+            return Err(RWAError::MintAlreadyCreated.into());
+        }
+        if self.instruction_datas.decimals != token_config.decimal {
+            return Err(RWAError::InvalidDecimals.into());
+        }
+        if self.instruction_datas.supply == 0 {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+        let extensions = self.instruction_datas.extensions;
+        if extensions & !CreateRWA::ALL_EXTENSIONS != 0 {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        let (mint_authority, mint_authority_bump) = find_program_address(
+            &[TokenConfig::MINT_AUTHORITY_SEED, self.accounts.token_config.key().as_ref()],
+            program_id,
+        );
+        if *self.accounts.mint_authority.key() != mint_authority {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let (freeze_authority, freeze_authority_bump) = find_program_address(
+            &[TokenConfig::FREEZE_AUTHORITY_SEED, self.accounts.token_config.key().as_ref()],
+            program_id,
+        );
+        if *self.accounts.freeze_authority.key() != freeze_authority {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        // Only ever signs through ForceTransfer, so no account is needed here
+        let permanent_delegate = (extensions & CreateRWA::PERMANENT_DELEGATE != 0).then(|| {
+            find_program_address(
+                &[TokenConfig::PERMANENT_DELEGATE_SEED, self.accounts.token_config.key().as_ref()],
+                program_id,
+            )
+        });
+
+        // Every RWA mint routes its transfers through our KYC hook
+        let mut mint_extensions = MintExtensions {
+            transfer_hook: Some(TransferHookInit {
+                authority: &mint_authority,
+                program_id,
+            }),
+            // Wallets and explorers find the TokenMetadata through the pointer, which points at the mint
+            metadata_pointer: Some(MetadataPointerInit {
+                authority: &mint_authority,
+                metadata_address: self.accounts.mint_account.key(),
+            }),
+            default_frozen: extensions & CreateRWA::DEFAULT_FROZEN != 0,
+            permanent_delegate: permanent_delegate.as_ref().map(|(delegate, _)| delegate),
+            ..MintExtensions::default()
+        };
+        if extensions & CreateRWA::TRANSFER_FEE != 0 {
+            // The mint authority PDA also controls the fee config and the withheld fees
+            mint_extensions.transfer_fee = Some(TransferFeeInit {
+                authority: &mint_authority,
+                fee_bps: token_config.transfer_fee_bps(),
+                maximum_fee: self.instruction_datas.maximum_transfer_fee,
+            });
+        }
+
+        Mint2022Account::init_with_extensions(
+            self.accounts.mint_account,
+            self.accounts.signer,
+            token_config.decimal,
+            &mint_authority,
+            Some(&freeze_authority),
+            &mint_extensions,
+        )?;
+
+        // The mint authority PDA is also the metadata update authority
+        let bump_ref = [mint_authority_bump];
+        let seeds = seeds!(TokenConfig::MINT_AUTHORITY_SEED, self.accounts.token_config.key().as_ref(), &bump_ref);
+
+        InitializeTokenMetadata {
+            mint: self.accounts.mint_account,
+            update_authority: self.accounts.mint_authority,
+            mint_authority: self.accounts.mint_authority,
+            name: trim_nul(&self.instruction_datas.name),
+            symbol: trim_nul(&self.instruction_datas.symbol),
+            uri: trim_nul(&self.instruction_datas.uri),
+        }
+        .invoke_signed(&[Signer::from(&seeds)])?;
+
+        for (key, value) in [
+            (ASSET_TYPE_KEY, trim_nul(&token_config.asset_type)),
+            (AUDIT_CID_KEY, trim_nul(&token_config.audit_cid)),
+        ] {
+            UpdateTokenMetadataField {
+                mint: self.accounts.mint_account,
+                update_authority: self.accounts.mint_authority,
+                field: MetadataField::Key(key),
+                value,
+            }
+            .invoke_signed(&[Signer::from(&seeds)])?;
+        }
+
+        // The metadata grew the mint past what CreateAccount funded
+        fund_rent_exemption(self.accounts.mint_account, self.accounts.signer)?;
+
+        ExtraAccountMetas::init(
+            self.accounts.signer,
+            self.accounts.extra_account_metas,
+            self.accounts.mint_account.key(),
+            self.accounts.token_config.key(),
+            program_id,
+        )?;
+
+        token_config.mint = *self.accounts.mint_account.key();
+        token_config.max_supply = self.instruction_datas.supply;
+        token_config.mint_authority_bump = mint_authority_bump;
+        token_config.freeze_authority_bump = freeze_authority_bump;
+        token_config.metadata_update_authority = *creator;
+        token_config.metadata_immutable = false;
+        if let Some((delegate, bump)) = permanent_delegate {
+            token_config.permanent_delegate = delegate;
+            token_config.permanent_delegate_bump = bump;
+        }
+
+        RwaCreated {
+            token_config: *self.accounts.token_config.key(),
+            mint: token_config.mint,
+            max_supply: token_config.max_supply,
+            decimals: token_config.decimal,
+            extensions,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
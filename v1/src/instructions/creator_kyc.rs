@@ -1,24 +1,24 @@
 use {
     crate::{
-        utils::{SignerAccount, ProgramAccount, load_acc_mut_unchecked, ProgramAccountInit},
-        states::CreatorKYC,
+        events::{Event, KycSubmitted},
+        instructions::{self, RWAInstruction, ZeroCopyTryFrom},
+        utils::{AccountCheck, SignerAccount, ProgramAccount, load_acc_mut_unchecked, ProgramAccountInit, DataLen},
+        states::{CreatorKYC, GlobalConfig, KycStatus},
         errors::RWAError
     },
     pinocchio::{
         account_info::AccountInfo,
-        pubkey::find_program_address,
+        pubkey::{find_program_address, Pubkey},
         program_error::ProgramError,
         seeds,
-        Signer,
     },
     core::convert::TryFrom,
-    core::io::Read,
-
 };
 
 /// Struct holding all relevant accounts for InitGlobalConfig
 pub struct CreatorKYCAccount<'a> {
     pub signer: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
     pub creator_kyc: &'a AccountInfo,
 }
 
@@ -26,29 +26,28 @@ impl<'a> TryFrom<&'a [AccountInfo]> for CreatorKYCAccount<'a> {
     type Error = ProgramError;
 
     fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [signer, creator_kyc] = infos else {
+        let [signer, global_config, creator_kyc] = infos else {
             return Err(RWAError::NotEnoughAccountKeys.into());
         };
-        let mut iter = infos.iter();
-
-        let signer = SignerAccount::try_from(signer);
+        SignerAccount::check(signer)?;
+        ProgramAccount::check(global_config)?;
 
-        Ok(Self { signer, &creator_kyc })
+        Ok(Self { signer, global_config, creator_kyc })
     }
 }
 
 /// Instruction wrapper
 pub struct CreatorKYCInstruction<'a> {
     pub accounts:  CreatorKYCAccount<'a>,
-    pub instruction_datas:  CreatorKYC,
+    pub instruction_datas: &'a instructions::CreatorKYC,
 }
 
 impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for CreatorKYCInstruction<'a> {
     type Error = ProgramError;
 
     fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
-        let accounts = CreatorKYCAccount::try_from(accounts);
-        let instruction_datas = CreatorKYC::try_from(data); // deserialize your instruction data
+        let accounts = CreatorKYCAccount::try_from(accounts)?;
+        let instruction_datas = instructions::CreatorKYC::try_from_bytes(data)?;
 
         Ok(Self {
             accounts,
@@ -58,31 +57,57 @@ impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for CreatorKYCInstruction<'a> {
 }
 
 impl<'a> CreatorKYCInstruction<'a> {
-    pub const DISCRIMINATOR: u8 = 4;
+    pub const DISCRIMINATOR: u8 = RWAInstruction::CreatorKYC as u8;
 
     /// Process the instruction: initialize the GlobalConfig PDA
     pub fn process(&mut self, program_id: &Pubkey) -> Result<(), ProgramError> {
+        GlobalConfig::check_open_account(self.accounts.global_config, program_id)?;
+
         // Derive the expected PDA
-        let (expected_pda, bump) = find_program_address(&[b"creator-kyc"], self.accounts.creator_kyc.key().as_ref(),  program_id);
+        // One KYC record per creator wallet
+        let (expected_pda, bump) = find_program_address(&[CreatorKYC::SEED_PREFIX, self.accounts.signer.key().as_ref()], program_id);
 
         // Check PDA matches the account passed in
         if *self.accounts.creator_kyc.key() != expected_pda {
             return Err(RWAError::InvalidAccountData.into());
         }
-        let seeds_array = seeds!(b"creator-kyc", self.accounts.creator_kyc.key().as_ref(), program_id);
-        let signer_pda = Signer::from(&seeds_array);
-
-        ProgramAccount::init_if_needed(self.accounts.signer, self.accounts.creator_kyc, signer_pda, size_of::<CreatorKYC>());
-
-        let creator_kyc_data = self.accounts.creator_kyc.try_borrow_mut_data()?;
-        let creator_kyc = unsafe { load_acc_mut_unchecked<CreatorKYC>(creator_kyc_data)};
-        // Write instruction data into the PDA
-        creator_kyc.name = self.instruction_datas.name.bytes();
-        creator_kyc.email_id = self.instruction_datas.email_id.bytes();
-        creator_kyc.wallet = *self.accounts.signer.key().as_ref();
-        //Default - false, until the config authority verifies the user_kyc by doing some offchain verification...
-        creator_kyc.verified = false;
-        creator_kyc.bump = self.instruction_datas.bump;
+        // An all-zero commitment can't be opened later, so it's no evidence at all
+        if self.instruction_datas.commitment == [0u8; 32] {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        let bump_ref = [bump];
+        let seeds_array = seeds!(CreatorKYC::SEED_PREFIX, self.accounts.signer.key().as_ref(), &bump_ref);
+
+        ProgramAccount::init_if_needed(self.accounts.signer, self.accounts.creator_kyc, &seeds_array, CreatorKYC::LEN)?;
+
+        let mut creator_kyc_data = self.accounts.creator_kyc.try_borrow_mut_data()?;
+        let creator_kyc: &mut CreatorKYC = unsafe { load_acc_mut_unchecked(&mut creator_kyc_data)? };
+        // Later rounds go through ResubmitCreatorKYC so the history counters survive
+        if creator_kyc.wallet != Pubkey::default() {
+            return Err(RWAError::InvalidKycStatus.into());
+        }
+        // Only the commitment goes on-chain, no PII
+        creator_kyc.commitment = self.instruction_datas.commitment;
+        creator_kyc.provider = self.instruction_datas.provider;
+        creator_kyc.wallet = *self.accounts.signer.key();
+        //Default - Pending, until the kyc authority verifies the user_kyc by doing some offchain verification...
+        creator_kyc.status = KycStatus::Pending as u8;
+        creator_kyc.reason_code = 0;
+        creator_kyc.decided_at = 0;
+        creator_kyc.expires_at = 0;
+        creator_kyc.submission_count = 1;
+        creator_kyc.approval_count = 0;
+        creator_kyc.bump = bump;
+
+        KycSubmitted {
+            creator: *self.accounts.signer.key(),
+            creator_kyc: *self.accounts.creator_kyc.key(),
+            commitment: creator_kyc.commitment,
+            provider: creator_kyc.provider,
+        }
+        .emit();
+
         Ok(())
     }
 }
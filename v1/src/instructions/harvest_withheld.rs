@@ -0,0 +1,119 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        seeds,
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, TransferFeesWithdrawn},
+        instructions::RWAInstruction,
+        states::TokenConfig,
+        utils::{
+            load_acc_unchecked, AccountCheck, HarvestWithheldTokensToMint, Mint2022Account, ProgramAccount,
+            SignerAccount, TokenAccount2022, WithdrawWithheldTokensFromMint, TOKEN_2022_PROGRAM_ID,
+        },
+    },
+};
+
+/// Accounts required to collect the transfer fees withheld on an asset.
+/// Every account after `token_program_2022` is a token account to harvest from.
+pub struct HarvestAndWithdrawWithheldAccounts<'a> {
+    pub issuer: &'a AccountInfo,          // token config creator
+    pub token_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub mint_authority: &'a AccountInfo,  // withdraw withheld authority set by CreateRWA
+    pub destination: &'a AccountInfo,
+    pub token_program_2022: &'a AccountInfo,
+    pub sources: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for HarvestAndWithdrawWithheldAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [issuer, token_config, mint, mint_authority, destination, token_program_2022, sources @ ..] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(issuer)?;
+        ProgramAccount::check(token_config)?;
+        Mint2022Account::check(mint)?;
+        if *token_program_2022.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        Ok(Self { issuer, token_config, mint, mint_authority, destination, token_program_2022, sources })
+    }
+}
+
+/// Instruction wrapper, takes no instruction data
+pub struct HarvestAndWithdrawWithheldInstruction<'a> {
+    pub accounts: HarvestAndWithdrawWithheldAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for HarvestAndWithdrawWithheldInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+        let accounts = HarvestAndWithdrawWithheldAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> HarvestAndWithdrawWithheldInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::HarvestAndWithdrawWithheld as u8;
+
+    /// Process the instruction: sweep withheld transfer fees into the mint, then pay them out to the issuer
+    pub fn process(&mut self, _program_id: &Pubkey) -> ProgramResult {
+        let token_config_data = self.accounts.token_config.try_borrow_data()?;
+        let token_config: &TokenConfig = unsafe { load_acc_unchecked(&token_config_data)? };
+
+        if token_config.creator != *self.accounts.issuer.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+        if token_config.mint != *self.accounts.mint.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        // Checked before any CPI so an oversized batch fails instead of logging a wrapped count
+        let harvested_accounts = u8::try_from(self.accounts.sources.len()).map_err(|_| RWAError::InvalidInstructionData)?;
+
+        for source in self.accounts.sources {
+            HarvestWithheldTokensToMint {
+                mint: self.accounts.mint,
+                source,
+            }
+            .invoke()?;
+        }
+
+        let before = TokenAccount2022::amount(self.accounts.destination)?;
+
+        let bump_ref = [token_config.mint_authority_bump];
+        let seeds = seeds!(TokenConfig::MINT_AUTHORITY_SEED, self.accounts.token_config.key().as_ref(), &bump_ref);
+
+        WithdrawWithheldTokensFromMint {
+            mint: self.accounts.mint,
+            destination: self.accounts.destination,
+            authority: self.accounts.mint_authority,
+        }
+        .invoke_signed(&[Signer::from(&seeds)])?;
+
+        TransferFeesWithdrawn {
+            mint: token_config.mint,
+            destination: *self.accounts.destination.key(),
+            amount: TokenAccount2022::amount(self.accounts.destination)?.saturating_sub(before),
+            harvested_accounts,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -2,58 +2,51 @@ use {
     core::convert::TryFrom,
     pinocchio::{
         account_info::AccountInfo,
-        instruction::Signer,
         program_error::ProgramError,
-        pubkey::Pubkey,
+        pubkey::{find_program_address, Pubkey},
         ProgramResult,
         seeds,
+        sysvars::{clock::Clock, Sysvar},
     },
     crate::{
         errors::RWAError,
-        instructions::{
-            CreatorKYC,
-            InitGlobalConfig,
-            InitTokenConfig,
+        events::{Event, TokenConfigCreated},
+        instructions::{InitTokenConfig, RWAInstruction, ZeroCopyTryFrom},
+        states::{CreatorKYC, GlobalConfig, TokenConfig, TokenConfigStatus, FEE_BPS_UNSET},
+        utils::{
+            load_acc_mut_unchecked, load_acc_unchecked, AccountCheck, DataLen, ProgramAccount, ProgramAccountInit,
+            SignerAccount,
         },
-        states::{GlobalConfig, TokenConfig},
-        utils::{ProgramAccount, SignerAccount, load_acc_mut_unchecked, ProgramAccountInit},
     },
 };
 
 /// Accounts required to initialize a token config
 pub struct TokenConfigAccounts<'a> {
-    pub signer: &'a AccountInfo,
+    pub signer: &'a AccountInfo,          // the creator, pays for the token config
     pub global_config: &'a AccountInfo,
-    pub creator_kyc: &'a AccountInfo,
-    pub token_config: &'a AccountInfo,
-
+    pub creator_kyc: &'a AccountInfo,     // the signer's own KYC record
+    pub token_config: &'a AccountInfo,    // created here, PDA of the creator KYC
+    pub system_program: &'a AccountInfo,
 }
 
 /// Instruction wrapper for InitTokenConfig
 pub struct InitTokenConfigInstruction<'a> {
     pub accounts: TokenConfigAccounts<'a>,
-    pub instruction_datas: InitTokenConfig,
+    pub instruction_datas: &'a InitTokenConfig,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for TokenConfigAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let mut iter = infos.iter();
-        let [signer, global_config, creator_kyc, token_config] = infos else {
-                    return Err(RWAError::NotEnoughAccountKeys.into());
+        let [signer, global_config, creator_kyc, token_config, system_program] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
         };
-        let signer = SignerAccount::try_from(&signer);
-        let global_config = ProgramAccount::try_from(global_config);
-        let creator_kyc = ProgramAccount::try_from(creator_kyc);
-        let token_config = ProgramAccount::try_from(token_config);
+        SignerAccount::check(signer)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(creator_kyc)?;
 
-        Ok(Self {
-            signer,
-            global_config,
-            creator_kyc,
-            token_config
-        })
+        Ok(Self { signer, global_config, creator_kyc, token_config, system_program })
     }
 }
 
@@ -61,8 +54,8 @@ impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for InitTokenConfigInstruction<'
     type Error = ProgramError;
 
     fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
-        let accounts = TokenConfigAccounts::try_from(accounts);
-        let instruction_datas = InitTokenConfig::try_from(data); // deserialize instruction data
+        let accounts = TokenConfigAccounts::try_from(accounts)?;
+        let instruction_datas = InitTokenConfig::try_from_bytes(data)?;
 
         Ok(Self {
             accounts,
@@ -72,41 +65,65 @@ impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for InitTokenConfigInstruction<'
 }
 
 impl<'a> InitTokenConfigInstruction<'a> {
-    pub const DISCRIMINATOR: u8 = 5;
+    pub const DISCRIMINATOR: u8 = RWAInstruction::InitTokenConfig as u8;
     pub const SEED_PREFIX: &'static [u8] = b"token-program";
 
+    /// Process the instruction: create the Pending token config of an approved creator
     pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
-        // Derive PDA for token config
-        let (expected_pda, bump) = Pubkey::find_program_address(&[Self::SEED_PREFIX], self.accounts.creator_kyc.key().as_ref(), program_id);
+        GlobalConfig::check_open_account(self.accounts.global_config, program_id)?;
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
 
-        if *self.accounts.token_config.key() != expected_pda {
-            return Err(ProgramError::InvalidAccountData);
+        if self.instruction_datas.decimals > global_config.max_decimal {
+            return Err(RWAError::InvalidDecimals.into());
         }
-        let seeds = seeds!(b"token-program", self.accounts.creator_kyc.key().as_ref(), core::mem::size_of::<TokenConfig>())?;
-        ProgramAccount::init_if_needed(self.accounts.signer, self.accounts.token_config, seeds,  core::mem::size_of::<TokenConfig>())?;
-
-        // Borrow PDA data mutably
-        let token_config_data = &mut self.accounts.token_config.try_borrow_mut_data()?;
-        let token_config: &mut TokenConfig = unsafe { load_acc_mut_unchecked(token_config_data)? };
 
-        let global_config_data = &mut self.accounts.global_config.try_borrow_mut_data()?;
-        let global_config: &mut GlobalConfig = unsafe { load_acc_mut_unchecked(global_config_data)? };
+        // Only the creator's own KYC record, otherwise anyone could borrow an approved one
+        let creator = self.accounts.signer.key();
+        let (expected_pda, _) = find_program_address(&[CreatorKYC::SEED_PREFIX, creator.as_ref()], program_id);
+        if *self.accounts.creator_kyc.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+        let creator_kyc_data = self.accounts.creator_kyc.try_borrow_data()?;
+        let creator_kyc: &CreatorKYC = unsafe { load_acc_unchecked(&creator_kyc_data)? };
+        creator_kyc.check_approved(Clock::get()?.unix_timestamp)?;
+
+        let (expected_pda, bump) = find_program_address(
+            &[Self::SEED_PREFIX, self.accounts.creator_kyc.key().as_ref()],
+            program_id,
+        );
+        if *self.accounts.token_config.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
 
-        if self.instruction_datas.decimals <= global_config.max_decimal {
-            return Err(RWAError::InvalidInstructionData.into())
-        };
+        // Fails if the config already exists, an existing one is never overwritten
+        let bump_ref = [bump];
+        let seeds = seeds!(Self::SEED_PREFIX, self.accounts.creator_kyc.key().as_ref(), &bump_ref);
+        ProgramAccount::init(self.accounts.signer, self.accounts.token_config, &seeds, TokenConfig::LEN)?;
 
-        let creator_kyc_data = &mut self.accounts.creator_kyc.try_borrow_mut_data()?;
-        let creator_kyc: &mut CreatorKYC = unsafe { load_acc_mut_unchecked(creator_kyc_data)? };
+        let mut token_config_data = self.accounts.token_config.try_borrow_mut_data()?;
+        let token_config: &mut TokenConfig = unsafe { load_acc_mut_unchecked(&mut token_config_data)? };
 
-        // Write instruction data into PDA struct
-        token_config.creator = self.instruction_datas.creator;
+        token_config.creator = *creator;
+        // Stays Pending/inactive until the config authority runs VerifyTokenConfig
         token_config.active = false;
+        token_config.status = TokenConfigStatus::Pending as u8;
         token_config.description = self.instruction_datas.description;
         token_config.asset_type = self.instruction_datas.asset_type;
         token_config.audit_cid = self.instruction_datas.audit_cid;
         token_config.bump = bump;
         token_config.decimal = self.instruction_datas.decimals;
+        token_config.issuance_fee_bps = FEE_BPS_UNSET;
+        token_config.redemption_fee_bps = FEE_BPS_UNSET;
+        token_config.transfer_fee_bps = FEE_BPS_UNSET;
+        token_config.fee_recipient = Pubkey::default();
+
+        TokenConfigCreated {
+            token_config: *self.accounts.token_config.key(),
+            creator: token_config.creator,
+            decimals: token_config.decimal,
+        }
+        .emit();
 
         Ok(())
     }
@@ -0,0 +1,111 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        seeds,
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, InvestorKycSubmitted},
+        instructions::{RWAInstruction, SubmitInvestorKYC, ZeroCopyTryFrom},
+        states::{AccreditationTier, InvestorKYC, KycStatus},
+        utils::{load_acc_mut_unchecked, DataLen, ProgramAccount, ProgramAccountInit, SignerAccount, AccountCheck},
+    },
+};
+
+/// Accounts required to submit an investor KYC record
+pub struct SubmitInvestorKYCAccounts<'a> {
+    pub investor: &'a AccountInfo,       // wallet being verified, pays for the PDA
+    pub investor_kyc: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SubmitInvestorKYCAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [investor, investor_kyc, system_program] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(investor)?;
+
+        Ok(Self { investor, investor_kyc, system_program })
+    }
+}
+
+/// Instruction wrapper
+pub struct SubmitInvestorKYCInstruction<'a> {
+    pub accounts: SubmitInvestorKYCAccounts<'a>,
+    pub instruction_datas: &'a SubmitInvestorKYC,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for SubmitInvestorKYCInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = SubmitInvestorKYCAccounts::try_from(accounts)?;
+        let instruction_datas = SubmitInvestorKYC::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> SubmitInvestorKYCInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::SubmitInvestorKYC as u8;
+
+    /// Process the instruction: open (or reopen) a Pending investor KYC record with the declared profile
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        // Validated here, the kyc_authority may still overwrite it on approval
+        AccreditationTier::try_from(self.instruction_datas.accreditation_tier)?;
+
+        let wallet = self.accounts.investor.key();
+        let (expected_pda, bump) = find_program_address(&[InvestorKYC::SEED_PREFIX, wallet.as_ref()], program_id);
+        if *self.accounts.investor_kyc.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+        let bump_ref = [bump];
+        let seeds = seeds!(InvestorKYC::SEED_PREFIX, wallet.as_ref(), &bump_ref);
+        ProgramAccount::init_if_needed(self.accounts.investor, self.accounts.investor_kyc, &seeds, InvestorKYC::LEN)?;
+
+        let mut investor_kyc_data = self.accounts.investor_kyc.try_borrow_mut_data()?;
+        let investor_kyc: &mut InvestorKYC = unsafe { load_acc_mut_unchecked(&mut investor_kyc_data)? };
+
+        // A fresh account is all zeroes, which reads as Pending with no wallet yet
+        // and an approval still in force doesn't need a new submission
+        let is_new = investor_kyc.wallet == Pubkey::default();
+        let in_force = match investor_kyc.status()? {
+            KycStatus::Pending => true,
+            KycStatus::Approved => Clock::get()?.unix_timestamp < investor_kyc.expires_at,
+            _ => false,
+        };
+        if !is_new && in_force {
+            return Err(RWAError::InvalidKycStatus.into());
+        }
+
+        investor_kyc.wallet = *wallet;
+        investor_kyc.jurisdiction = self.instruction_datas.jurisdiction;
+        investor_kyc.accreditation_tier = self.instruction_datas.accreditation_tier;
+        investor_kyc.status = KycStatus::Pending as u8;
+        investor_kyc.reason_code = 0;
+        investor_kyc.decided_at = 0;
+        investor_kyc.expires_at = 0;
+        investor_kyc.bump = bump;
+
+        InvestorKycSubmitted {
+            investor: *wallet,
+            investor_kyc: *self.accounts.investor_kyc.key(),
+            jurisdiction: investor_kyc.jurisdiction,
+            accreditation_tier: investor_kyc.accreditation_tier,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        seeds,
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, RwaMetadataFrozen},
+        instructions::RWAInstruction,
+        states::TokenConfig,
+        utils::{
+            load_acc_mut_unchecked, AccountCheck, ProgramAccount, SignerAccount, UpdateTokenMetadataAuthority,
+            TOKEN_2022_PROGRAM_ID,
+        },
+    },
+};
+
+/// Accounts required to freeze an RWA's metadata
+pub struct MakeImmutableAccounts<'a> {
+    pub update_authority: &'a AccountInfo, // TokenConfig.metadata_update_authority
+    pub token_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub mint_authority: &'a AccountInfo,
+    pub token_program_2022: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for MakeImmutableAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [update_authority, token_config, mint, mint_authority, token_program_2022] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(update_authority)?;
+        ProgramAccount::check(token_config)?;
+        if *token_program_2022.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        Ok(Self { update_authority, token_config, mint, mint_authority, token_program_2022 })
+    }
+}
+
+/// Instruction wrapper
+pub struct MakeImmutableInstruction<'a> {
+    pub accounts: MakeImmutableAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for MakeImmutableInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((_data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = MakeImmutableAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> MakeImmutableInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::MakeImmutable as u8;
+
+    /// Process the instruction: lock the metadata for good, on our side and on the mint
+    pub fn process(&mut self, _program_id: &Pubkey) -> ProgramResult {
+        let mut token_config_data = self.accounts.token_config.try_borrow_mut_data()?;
+        let token_config: &mut TokenConfig = unsafe { load_acc_mut_unchecked(&mut token_config_data)? };
+
+        if token_config.mint == Pubkey::default() || token_config.mint != *self.accounts.mint.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+        if token_config.metadata_update_authority != *self.accounts.update_authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+        if token_config.metadata_immutable {
+            return Err(RWAError::MetadataImmutable.into());
+        }
+
+        // Dropping the Token-2022 update authority makes the lock visible to wallets too
+        let bump_ref = [token_config.mint_authority_bump];
+        let seeds = seeds!(TokenConfig::MINT_AUTHORITY_SEED, self.accounts.token_config.key().as_ref(), &bump_ref);
+
+        UpdateTokenMetadataAuthority {
+            mint: self.accounts.mint,
+            update_authority: self.accounts.mint_authority,
+            new_authority: None,
+        }
+        .invoke_signed(&[Signer::from(&seeds)])?;
+
+        token_config.metadata_immutable = true;
+
+        RwaMetadataFrozen {
+            mint: token_config.mint,
+            update_authority: token_config.metadata_update_authority,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -1,9 +1,233 @@
 use {
-    pinocchio::account_info::AccountInfo,
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        seeds,
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, FeeCollected, FeeKind, RwaMinted},
+        instructions::{MintRWA, RWAInstruction, ZeroCopyTryFrom},
+        states::{CreatorKYC, GlobalConfig, HolderStatus, InvestorKYC, TokenConfig},
+        utils::{
+            load_acc_unchecked, AccountCheck, AssociatedTokenAccount, AssociatedTokenAccountInit,
+            Mint2022Account, MintToChecked, ProgramAccount, SignerAccount, TokenAccount2022, TOKEN_2022_PROGRAM_ID,
+        },
+    },
 };
 
-#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct MintRWAAccount<'a> {
-    pub signer: &'a AccountInfo,
-}
\ No newline at end of file
+    pub signer: &'a AccountInfo,          // issuer, must be the token config creator, pays for the ATA
+    pub global_config: &'a AccountInfo,
+    pub creator_kyc: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub mint_authority: &'a AccountInfo,  // program PDA that signs the mint
+    pub freeze_authority: &'a AccountInfo, // program PDA that thaws the fresh ATAs
+    pub investor: &'a AccountInfo,
+    pub investor_ata: &'a AccountInfo,
+    pub investor_kyc: &'a AccountInfo,
+    pub holder_status: &'a AccountInfo,   // the investor's legal hold record, may not exist
+    pub fee_recipient: &'a AccountInfo,   // fee vault PDA, or the asset's own fee_recipient
+    pub fee_recipient_ata: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program_2022: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for MintRWAAccount<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [signer, global_config, creator_kyc, token_config, mint, mint_authority, freeze_authority, investor, investor_ata, investor_kyc, holder_status, fee_recipient, fee_recipient_ata, system_program, token_program_2022, _associated_token_program] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(signer)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(creator_kyc)?;
+        ProgramAccount::check(token_config)?;
+        Mint2022Account::check(mint)?;
+        if *token_program_2022.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        Ok(Self { signer, global_config, creator_kyc, token_config, mint, mint_authority, freeze_authority, investor, investor_ata, investor_kyc, holder_status, fee_recipient, fee_recipient_ata, system_program, token_program_2022 })
+    }
+}
+
+/// Instruction wrapper
+pub struct MintRWAInstruction<'a> {
+    pub accounts: MintRWAAccount<'a>,
+    pub instruction_datas: &'a MintRWA,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for MintRWAInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = MintRWAAccount::try_from(accounts)?;
+        let instruction_datas = MintRWA::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> MintRWAInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::MintRWA as u8;
+
+    /// Process the instruction: mint `amount` to the investor's ATA without crossing the max supply
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        GlobalConfig::check_open_account(self.accounts.global_config, program_id)?;
+
+        let amount = self.instruction_datas.amount;
+        if amount == 0 {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+
+        // The issuer's own KYC has to be current, not just the investor's
+        let (expected_pda, _) = find_program_address(&[CreatorKYC::SEED_PREFIX, self.accounts.signer.key().as_ref()], program_id);
+        if *self.accounts.creator_kyc.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+        let creator_kyc_data = self.accounts.creator_kyc.try_borrow_data()?;
+        let creator_kyc: &CreatorKYC = unsafe { load_acc_unchecked(&creator_kyc_data)? };
+        creator_kyc.check_approved(Clock::get()?.unix_timestamp)?;
+
+        let token_config_data = self.accounts.token_config.try_borrow_data()?;
+        let token_config: &TokenConfig = unsafe { load_acc_unchecked(&token_config_data)? };
+
+        if token_config.creator != *self.accounts.signer.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+        if !token_config.active {
+            return Err(RWAError::TokenConfigNotActive.into());
+        }
+        if token_config.mint != *self.accounts.mint.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        // Issuance fee is minted on top of `amount` to the fee recipient, and counts toward max_supply
+        let fee = token_config.issuance_fee(global_config, amount);
+        token_config.check_fee_recipient(self.accounts.fee_recipient, program_id)?;
+
+        // Securities can only be issued to eligible investors
+        InvestorKYC::check_eligible(self.accounts.investor_kyc, self.accounts.investor.key(), token_config, program_id)?;
+
+        // Tokens burned by pending redemptions may be minted back, their room stays reserved
+        let supply = Mint2022Account::supply(self.accounts.mint)?;
+        let issued = amount.checked_add(fee).ok_or(RWAError::MaxSupplyExceeded)?;
+        let new_supply = token_config.check_max_supply(supply, issued)?;
+
+        let investor_ata_is_new = self.accounts.investor_ata.data_is_empty();
+        // A new ATA of a holder under a legal hold stays frozen, only ThawHolder may lift it
+        if investor_ata_is_new {
+            let (expected_pda, _) = find_program_address(
+                &[HolderStatus::SEED_PREFIX, token_config.mint.as_ref(), self.accounts.investor.key().as_ref()],
+                program_id,
+            );
+            if *self.accounts.holder_status.key() != expected_pda {
+                return Err(RWAError::PdaMismatch.into());
+            }
+            if self.accounts.holder_status.is_owned_by(program_id) {
+                let holder_status_data = self.accounts.holder_status.try_borrow_data()?;
+                let holder_status: &HolderStatus = unsafe { load_acc_unchecked(&holder_status_data)? };
+                if holder_status.frozen {
+                    return Err(RWAError::HolderFrozen.into());
+                }
+            }
+        }
+        AssociatedTokenAccount::init_if_needed(
+            self.accounts.investor_ata,
+            self.accounts.mint,
+            self.accounts.signer,
+            self.accounts.investor,
+            self.accounts.system_program,
+            self.accounts.token_program_2022,
+        )?;
+
+        // Both authority PDAs were derived and their bumps stored by CreateRWA
+        let bump_ref = [token_config.mint_authority_bump];
+        let seeds = seeds!(TokenConfig::MINT_AUTHORITY_SEED, self.accounts.token_config.key().as_ref(), &bump_ref);
+        let signer = Signer::from(&seeds);
+        let freeze_bump_ref = [token_config.freeze_authority_bump];
+        let freeze_seeds = seeds!(TokenConfig::FREEZE_AUTHORITY_SEED, self.accounts.token_config.key().as_ref(), &freeze_bump_ref);
+
+        // On a DefaultAccountState=Frozen mint a new ATA starts frozen, the investor just passed KYC.
+        // An existing frozen ATA is under a legal hold and Token-2022 refuses to mint into it.
+        if investor_ata_is_new {
+            TokenAccount2022::thaw_if_frozen(
+                self.accounts.investor_ata,
+                self.accounts.mint,
+                self.accounts.freeze_authority,
+                &[Signer::from(&freeze_seeds)],
+            )?;
+        }
+
+        MintToChecked {
+            mint: self.accounts.mint,
+            account: self.accounts.investor_ata,
+            mint_authority: self.accounts.mint_authority,
+            amount,
+            decimals: token_config.decimal,
+        }
+        .invoke_signed(&[signer])?;
+
+        if fee > 0 {
+            let fee_recipient_ata_is_new = self.accounts.fee_recipient_ata.data_is_empty();
+            AssociatedTokenAccount::init_if_needed(
+                self.accounts.fee_recipient_ata,
+                self.accounts.mint,
+                self.accounts.signer,
+                self.accounts.fee_recipient,
+                self.accounts.system_program,
+                self.accounts.token_program_2022,
+            )?;
+            if fee_recipient_ata_is_new {
+                TokenAccount2022::thaw_if_frozen(
+                    self.accounts.fee_recipient_ata,
+                    self.accounts.mint,
+                    self.accounts.freeze_authority,
+                    &[Signer::from(&freeze_seeds)],
+                )?;
+            }
+
+            MintToChecked {
+                mint: self.accounts.mint,
+                account: self.accounts.fee_recipient_ata,
+                mint_authority: self.accounts.mint_authority,
+                amount: fee,
+                decimals: token_config.decimal,
+            }
+            .invoke_signed(&[Signer::from(&seeds)])?;
+
+            FeeCollected {
+                mint: *self.accounts.mint.key(),
+                fee_ata: *self.accounts.fee_recipient_ata.key(),
+                amount: fee,
+                kind: FeeKind::Issuance as u8,
+            }
+            .emit();
+        }
+
+        RwaMinted {
+            mint: *self.accounts.mint.key(),
+            investor: *self.accounts.investor.key(),
+            amount,
+            supply: new_supply,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
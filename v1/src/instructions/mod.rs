@@ -5,6 +5,7 @@ use {
     },
     crate::{
         errors::RWAError,
+        states::MAX_JURISDICTIONS,
         utils::{load_ix_data, DataLen}
     },
 };
@@ -12,44 +13,345 @@ use {
 pub mod create_rwa;
 pub use create_rwa::*;
 
+pub mod update_rwa_metadata;
+pub use update_rwa_metadata::*;
+
+pub mod make_immutable;
+pub use make_immutable::*;
+
 pub mod creator_kyc;
 pub use creator_kyc::*;
 
+pub mod resubmit_creator_kyc;
+pub use resubmit_creator_kyc::*;
+
+pub mod close_creator_kyc;
+pub use close_creator_kyc::*;
+
+pub mod investor_kyc;
+pub use investor_kyc::*;
+
 pub mod init_token_config;
 pub use init_token_config::*;
 
+pub mod close_token_config;
+pub use close_token_config::*;
+
 pub mod mint_rwa;
 pub use mint_rwa::*;
 
+pub mod thaw_investor_account;
+pub use thaw_investor_account::*;
+
+pub mod redeem;
+pub use redeem::*;
+
+pub mod settle_redemption;
+pub use settle_redemption::*;
+
+pub mod harvest_withheld;
+pub use harvest_withheld::*;
+
+pub mod transfer_hook;
+pub use transfer_hook::*;
+
 pub mod admin;
 pub use admin::*;
 
+pub mod batch;
+pub use batch::*;
+
 
 /// Zero-copy trait
 pub trait ZeroCopyTryFrom<'a>: Sized {
     fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError>;
 }
 
+/// Flags are sent as a u8, a `bool` read straight from instruction bytes would be UB past 1
+fn flag(byte: u8) -> Result<bool, ProgramError> {
+    match byte {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(RWAError::InvalidInstructionData.into()),
+    }
+}
+
 
-/// Instruction enum
+/// Instruction enum, the single source of truth for discriminators.
+/// Every handler's `DISCRIMINATOR` and the entrypoint dispatch are derived from it.
 #[repr(u8)]
-#[derive(shank::ShankInstruction)]
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankInstruction)]
 pub enum RWAInstruction {
-    #[account(0, name = "Signer")]
-    InitGlobalConfig = 0,
+    #[account(0, writable, signer, name = "config_authority", desc = "Config authority, pays for the PDA")]
+    #[account(1, signer, name = "kyc_authority")]
+    #[account(2, writable, name = "global_config")]
+    #[account(3, name = "system_program")]
+    InitGlobalConfig   = 0,
+
+    #[account(0, signer, name = "config_authority")]
+    #[account(1, writable, name = "global_config")]
+    UpdateGlobalConfig = 1,
+
+    #[account(0, writable, signer, name = "creator")]
+    #[account(1, name = "global_config")]
+    #[account(2, writable, name = "creator_kyc")]
+    CreatorKYC         = 2,
+
+    #[account(0, signer, name = "kyc_authority")]
+    #[account(1, name = "global_config")]
+    #[account(2, writable, name = "creator_kyc")]
+    VerifyCreatorKYC   = 3,
+
+    #[account(0, writable, signer, name = "creator")]
+    #[account(1, name = "global_config")]
+    #[account(2, name = "creator_kyc")]
+    #[account(3, writable, name = "token_config")]
+    #[account(4, name = "system_program")]
+    InitTokenConfig    = 4,
+
+    #[account(0, signer, name = "config_authority")]
+    #[account(1, name = "global_config")]
+    #[account(2, writable, name = "token_config")]
+    VerifyTokenConfig  = 5,
+
+    #[account(0, writable, signer, name = "creator")]
+    #[account(1, name = "global_config")]
+    #[account(2, name = "creator_kyc")]
+    #[account(3, writable, name = "token_config")]
+    #[account(4, writable, signer, name = "mint")]
+    #[account(5, writable, name = "extra_account_metas")]
+    #[account(6, name = "mint_authority")]
+    #[account(7, name = "freeze_authority")]
+    #[account(8, name = "system_program")]
+    #[account(9, name = "token_program")]
+    CreateRWA          = 6,
+
+    #[account(0, writable, signer, name = "issuer")]
+    #[account(1, name = "global_config")]
+    #[account(2, name = "creator_kyc")]
+    #[account(3, name = "token_config")]
+    #[account(4, writable, name = "mint")]
+    #[account(5, name = "mint_authority")]
+    #[account(6, name = "freeze_authority")]
+    #[account(7, name = "investor")]
+    #[account(8, writable, name = "investor_ata")]
+    #[account(9, name = "investor_kyc")]
+    #[account(10, name = "holder_status")]
+    #[account(11, name = "fee_recipient")]
+    #[account(12, writable, name = "fee_recipient_ata")]
+    #[account(13, name = "system_program")]
+    #[account(14, name = "token_program")]
+    #[account(15, name = "associated_token_program")]
+    MintRWA            = 7,
+
+    #[account(0, signer, name = "authority")]
+    #[account(1, writable, name = "global_config")]
+    ProposeAuthority   = 8,
+
+    #[account(0, signer, name = "new_authority")]
+    #[account(1, writable, name = "global_config")]
+    AcceptAuthority    = 9,
+
+    #[account(0, signer, name = "authority")]
+    #[account(1, writable, name = "global_config")]
+    CancelAuthority    = 10,
+
+    #[account(0, writable, signer, name = "holder")]
+    #[account(1, name = "global_config")]
+    #[account(2, writable, name = "token_config")]
+    #[account(3, writable, name = "mint")]
+    #[account(4, writable, name = "holder_ata")]
+    #[account(5, writable, name = "redemption")]
+    #[account(6, name = "fee_recipient")]
+    #[account(7, writable, name = "fee_recipient_ata")]
+    #[account(8, name = "freeze_authority")]
+    #[account(9, name = "system_program")]
+    #[account(10, name = "token_program")]
+    #[account(11, name = "associated_token_program")]
+    #[account(12, name = "extra_account_metas")]
+    #[account(13, name = "holder_kyc")]
+    #[account(14, name = "fee_recipient_kyc")]
+    #[account(15, name = "hook_program")]
+    Redeem             = 11,
+
+    #[account(0, signer, name = "issuer")]
+    #[account(1, writable, name = "token_config")]
+    #[account(2, writable, name = "redemption")]
+    #[account(3, writable, name = "mint")]
+    #[account(4, writable, name = "holder_ata")]
+    #[account(5, name = "mint_authority")]
+    #[account(6, name = "token_program")]
+    SettleRedemption   = 12,
+
+    #[account(0, signer, name = "config_authority")]
+    #[account(1, name = "global_config")]
+    #[account(2, name = "mint")]
+    #[account(3, name = "fee_vault")]
+    #[account(4, writable, name = "fee_vault_ata")]
+    #[account(5, writable, name = "destination")]
+    #[account(6, name = "token_program")]
+    #[account(7, name = "extra_account_metas")]
+    #[account(8, name = "token_config")]
+    #[account(9, name = "fee_vault_kyc")]
+    #[account(10, name = "destination_kyc")]
+    #[account(11, name = "hook_program")]
+    WithdrawFees       = 13,
+
+    #[account(0, signer, name = "config_authority")]
+    #[account(1, name = "global_config")]
+    #[account(2, writable, name = "token_config")]
+    SetTokenConfigFees = 14,
+
+    #[account(0, signer, name = "issuer")]
+    #[account(1, name = "token_config")]
+    #[account(2, writable, name = "mint")]
+    #[account(3, name = "mint_authority")]
+    #[account(4, writable, name = "destination")]
+    #[account(5, name = "token_program")]
+    // Followed by the token accounts to harvest withheld fees from
+    HarvestAndWithdrawWithheld = 15,
+
+    #[account(0, writable, signer, name = "investor")]
+    #[account(1, writable, name = "investor_kyc")]
+    #[account(2, name = "system_program")]
+    SubmitInvestorKYC  = 16,
+
+    #[account(0, signer, name = "kyc_authority")]
+    #[account(1, name = "global_config")]
+    #[account(2, writable, name = "investor_kyc")]
+    ApproveInvestorKYC = 17,
+
+    #[account(0, signer, name = "kyc_authority")]
+    #[account(1, name = "global_config")]
+    #[account(2, writable, name = "investor_kyc")]
+    RevokeInvestorKYC  = 18,
+
+    #[account(0, signer, name = "config_authority")]
+    #[account(1, name = "global_config")]
+    #[account(2, writable, name = "token_config")]
+    SetTokenConfigRules = 19,
 
-    #[account(0, name = "Signer")]
-    InitTokenConfig  = 1,
+    #[account(0, signer, name = "creator")]
+    #[account(1, writable, name = "creator_kyc")]
+    ResubmitCreatorKYC = 20,
 
-    #[account(0, name = "Signer")]
-    CreateRWA        = 2,
+    #[account(0, signer, name = "authority")]
+    #[account(1, name = "global_config")]
+    #[account(2, writable, name = "creator_kyc")]
+    #[account(3, name = "token_config")]
+    #[account(4, writable, name = "destination")]
+    CloseCreatorKYC    = 21,
 
-    #[account(0, name = "Signer")]
-    MintRWA          = 3,
+    #[account(0, signer, name = "authority")]
+    #[account(1, name = "global_config")]
+    #[account(2, writable, name = "token_config")]
+    #[account(3, name = "mint")]
+    #[account(4, writable, name = "destination")]
+    CloseTokenConfig   = 22,
 
-    #[account(0, name = "Signer")]
-    CreatorKYC       = 4,
+    #[account(0, writable, signer, name = "update_authority")]
+    #[account(1, name = "token_config")]
+    #[account(2, writable, name = "mint")]
+    #[account(3, name = "mint_authority")]
+    #[account(4, name = "system_program")]
+    #[account(5, name = "token_program")]
+    UpdateRWAMetadata  = 23,
 
+    #[account(0, signer, name = "update_authority")]
+    #[account(1, writable, name = "token_config")]
+    #[account(2, writable, name = "mint")]
+    #[account(3, name = "mint_authority")]
+    #[account(4, name = "token_program")]
+    MakeImmutable      = 24,
+
+    #[account(0, signer, name = "signer")]
+    #[account(1, name = "token_config")]
+    #[account(2, name = "mint")]
+    #[account(3, writable, name = "token_account")]
+    #[account(4, name = "investor_kyc")]
+    #[account(5, name = "holder_status")]
+    #[account(6, name = "freeze_authority")]
+    #[account(7, name = "token_program")]
+    ThawInvestorAccount = 25,
+
+    #[account(0, writable, signer, name = "authority")]
+    #[account(1, name = "global_config")]
+    #[account(2, name = "token_config")]
+    #[account(3, name = "mint")]
+    #[account(4, writable, name = "token_account")]
+    #[account(5, writable, name = "holder_status")]
+    #[account(6, name = "freeze_authority")]
+    #[account(7, name = "system_program")]
+    #[account(8, name = "token_program")]
+    FreezeHolder       = 26,
+
+    #[account(0, signer, name = "authority")]
+    #[account(1, name = "global_config")]
+    #[account(2, name = "token_config")]
+    #[account(3, name = "mint")]
+    #[account(4, writable, name = "token_account")]
+    #[account(5, writable, name = "holder_status")]
+    #[account(6, name = "freeze_authority")]
+    #[account(7, name = "token_program")]
+    ThawHolder         = 27,
+
+    #[account(0, signer, name = "transfer_agent")]
+    #[account(1, name = "global_config")]
+    #[account(2, name = "token_config")]
+    #[account(3, name = "mint")]
+    #[account(4, writable, name = "source")]
+    #[account(5, writable, name = "destination")]
+    #[account(6, name = "permanent_delegate")]
+    #[account(7, name = "freeze_authority")]
+    #[account(8, name = "token_program")]
+    #[account(9, name = "extra_account_metas")]
+    #[account(10, name = "source_kyc")]
+    #[account(11, name = "destination_kyc")]
+    #[account(12, name = "hook_program")]
+    ForceTransfer      = 28,
+
+    // Accounts are the concatenation of every sub-instruction's accounts
+    Batch              = 255,
+}
+
+impl TryFrom<u8> for RWAInstruction {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(RWAInstruction::InitGlobalConfig),
+            1 => Ok(RWAInstruction::UpdateGlobalConfig),
+            2 => Ok(RWAInstruction::CreatorKYC),
+            3 => Ok(RWAInstruction::VerifyCreatorKYC),
+            4 => Ok(RWAInstruction::InitTokenConfig),
+            5 => Ok(RWAInstruction::VerifyTokenConfig),
+            6 => Ok(RWAInstruction::CreateRWA),
+            7 => Ok(RWAInstruction::MintRWA),
+            8 => Ok(RWAInstruction::ProposeAuthority),
+            9 => Ok(RWAInstruction::AcceptAuthority),
+            10 => Ok(RWAInstruction::CancelAuthority),
+            11 => Ok(RWAInstruction::Redeem),
+            12 => Ok(RWAInstruction::SettleRedemption),
+            13 => Ok(RWAInstruction::WithdrawFees),
+            14 => Ok(RWAInstruction::SetTokenConfigFees),
+            15 => Ok(RWAInstruction::HarvestAndWithdrawWithheld),
+            16 => Ok(RWAInstruction::SubmitInvestorKYC),
+            17 => Ok(RWAInstruction::ApproveInvestorKYC),
+            18 => Ok(RWAInstruction::RevokeInvestorKYC),
+            19 => Ok(RWAInstruction::SetTokenConfigRules),
+            20 => Ok(RWAInstruction::ResubmitCreatorKYC),
+            21 => Ok(RWAInstruction::CloseCreatorKYC),
+            22 => Ok(RWAInstruction::CloseTokenConfig),
+            23 => Ok(RWAInstruction::UpdateRWAMetadata),
+            24 => Ok(RWAInstruction::MakeImmutable),
+            25 => Ok(RWAInstruction::ThawInvestorAccount),
+            26 => Ok(RWAInstruction::FreezeHolder),
+            27 => Ok(RWAInstruction::ThawHolder),
+            28 => Ok(RWAInstruction::ForceTransfer),
+            255 => Ok(RWAInstruction::Batch),
+            _ => Err(RWAError::InvalidInstructionData.into()),
+        }
+    }
 }
 
 /// Init Global Config
@@ -58,8 +360,14 @@ pub struct InitGlobalConfig {
     pub bump: u8,
     pub open_time: u64,
     pub max_decimal: u8,
-    pub fees_bps: u8,
-    pub active: bool,
+    pub fees_bps: u16,
+    pub active: u8,   // 0 or 1
+}
+
+impl InitGlobalConfig {
+    pub fn active(&self) -> Result<bool, ProgramError> {
+        flag(self.active)
+    }
 }
 
 impl DataLen for InitGlobalConfig {
@@ -72,17 +380,95 @@ impl<'a> ZeroCopyTryFrom<'a> for InitGlobalConfig {
     }
 }
 
+/// Update Global Config
+/// Only the fields whose bit is set in `mask` are written, the rest are ignored.
+#[repr(C)]
+pub struct UpdateGlobalConfig {
+    pub mask: u8,
+    pub active: u8,   // 0 or 1
+    pub open_time: u64,
+    pub max_decimal: u8,
+    pub fees_bps: u16,
+}
+
+impl UpdateGlobalConfig {
+    pub const ACTIVE: u8 = 1 << 0;
+    pub const OPEN_TIME: u8 = 1 << 1;
+    pub const MAX_DECIMAL: u8 = 1 << 2;
+    pub const FEES_BPS: u8 = 1 << 3;
+    pub const ALL: u8 = Self::ACTIVE | Self::OPEN_TIME | Self::MAX_DECIMAL | Self::FEES_BPS;
+
+    pub fn active(&self) -> Result<bool, ProgramError> {
+        flag(self.active)
+    }
+}
+
+impl DataLen for UpdateGlobalConfig {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for UpdateGlobalConfig {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Propose a new config/kyc/compliance/transfer agent authority, `kind` is a `states::AuthorityKind`
+#[repr(C)]
+pub struct ProposeAuthority {
+    pub kind: u8,
+    pub new_authority: Pubkey,
+}
+
+impl DataLen for ProposeAuthority {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for ProposeAuthority {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Accept a pending authority, signed by the proposed key
+#[repr(C)]
+pub struct AcceptAuthority {
+    pub kind: u8,
+}
+
+impl DataLen for AcceptAuthority {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for AcceptAuthority {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Drop a pending authority, signed by the current one
+#[repr(C)]
+pub struct CancelAuthority {
+    pub kind: u8,
+}
+
+impl DataLen for CancelAuthority {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for CancelAuthority {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
 #[repr(C)]
 pub struct InitTokenConfig {
-    pub bump: u8, 
-    pub creator: Pubkey,         // 32 bytes
-    pub mint: Pubkey,
+    // The creator is the signer and the mint comes from CreateRWA, neither is taken from the data
     pub decimals: u8,
-    //Default: false - it is set to true flag after the developers verify this token is legitimate
     pub description: [u8; 128],  // fixed max length (128 chars)
     pub asset_type: [u8; 8],          // e.g. gold, real_estate or some sort of physical asset
     pub audit_cid: [u8; 32],     // compact audit link (like IPFS CID)
-    pub active: bool,
 }
 
 impl DataLen for InitTokenConfig {
@@ -94,12 +480,11 @@ impl<'a> ZeroCopyTryFrom<'a> for InitTokenConfig {
         unsafe { load_ix_data::<Self>(data) }
     }
 }
-/// Init Creator KYC
+/// Init Creator KYC, `commitment` is sha256(dossier || salt) computed off-chain
 #[repr(C)]
 pub struct CreatorKYC {
-    pub name:  [u8; 32],   // fixed-length, UTF-8 string
-    pub email:  [u8; 32],  // fixed-length, UTF-8 string
-    pub wallet:  [u8; 32],
+    pub commitment: [u8; 32],
+    pub provider: [u8; 16],  // fixed-length ASCII
 }
 
 impl DataLen for CreatorKYC {
@@ -112,11 +497,78 @@ impl<'a> ZeroCopyTryFrom<'a> for CreatorKYC {
     }
 }
 
+/// Resubmit Creator KYC, the renewed dossier gets a fresh commitment
+#[repr(C)]
+pub struct ResubmitCreatorKYC {
+    pub commitment: [u8; 32],
+    pub provider: [u8; 16],
+}
+
+impl DataLen for ResubmitCreatorKYC {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for ResubmitCreatorKYC {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Verify Creator KYC, `decision` is a `states::KycDecision`.
+/// `expires_at` is required on Approve and must be 0 otherwise.
+#[repr(C)]
+pub struct VerifyCreatorKYC {
+    pub expires_at: i64,
+    pub decision: u8,
+    pub reason_code: u8,
+}
+
+impl DataLen for VerifyCreatorKYC {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for VerifyCreatorKYC {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Verify Token Config, `action` is a `states::TokenConfigAction`
+#[repr(C)]
+pub struct VerifyTokenConfig {
+    pub action: u8,
+}
+
+impl DataLen for VerifyTokenConfig {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for VerifyTokenConfig {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
 /// Struct for Create RWA
 #[repr(C)]
 pub struct CreateRWA {
-    pub supply: u64,
-    pub decimals: u8,
+    pub supply: u64,             // max supply that MintRWA may ever issue
+    pub maximum_transfer_fee: u64, // per-transfer cap, only read with TRANSFER_FEE
+    pub decimals: u8,            // must match TokenConfig.decimal
+    pub extensions: u8,          // bitmask of the optional Token-2022 extensions below
+    pub name: [u8; 32],          // fixed-length, UTF-8 string
+    pub symbol: [u8; 10],        // fixed-length, UTF-8 string
+    pub uri: [u8; 200],          // fixed-length, UTF-8 string
+}
+
+impl CreateRWA {
+    /// TransferFeeConfig at the asset's transfer fee rate, withheld fees go to the issuer
+    pub const TRANSFER_FEE: u8 = 1;
+    /// DefaultAccountState=Frozen, holders are thawed once their KYC checks out
+    pub const DEFAULT_FROZEN: u8 = 2;
+    /// PermanentDelegate set to a program PDA, enables ForceTransfer
+    pub const PERMANENT_DELEGATE: u8 = 4;
+    pub const ALL_EXTENSIONS: u8 = Self::TRANSFER_FEE | Self::DEFAULT_FROZEN | Self::PERMANENT_DELEGATE;
 }
 
 impl DataLen for CreateRWA {
@@ -145,3 +597,217 @@ impl<'a> ZeroCopyTryFrom<'a> for MintRWA {
         unsafe { load_ix_data::<Self>(data) }
     }
 }
+
+/// Redeem
+#[repr(C)]
+pub struct Redeem {
+    pub amount: u64,
+    pub nonce: u64,
+}
+
+impl DataLen for Redeem {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for Redeem {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Settle Redemption, `settled` 0 means rejected and refunded
+#[repr(C)]
+pub struct SettleRedemption {
+    pub settled: u8,   // 0 or 1
+    pub reason_code: u8,
+}
+
+impl SettleRedemption {
+    pub fn settled(&self) -> Result<bool, ProgramError> {
+        flag(self.settled)
+    }
+}
+
+impl DataLen for SettleRedemption {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for SettleRedemption {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Withdraw Fees, `amount` in base units of the fee vault's mint
+#[repr(C)]
+pub struct WithdrawFees {
+    pub amount: u64,
+}
+
+impl DataLen for WithdrawFees {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for WithdrawFees {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Set Token Config Fees, each bps is either <= 10_000 or `FEE_BPS_UNSET`.
+/// A default `fee_recipient` sends the fees to the program fee vault.
+#[repr(C)]
+pub struct SetTokenConfigFees {
+    pub issuance_fee_bps: u16,
+    pub redemption_fee_bps: u16,
+    pub transfer_fee_bps: u16,  // must repeat the current rate once the mint exists
+    pub fee_recipient: Pubkey,
+}
+
+impl DataLen for SetTokenConfigFees {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for SetTokenConfigFees {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Transfer hook Execute, sent by Token-2022 after the 8 byte interface discriminator
+#[repr(C)]
+pub struct TransferHookExecute {
+    pub amount: u64,
+}
+
+impl DataLen for TransferHookExecute {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for TransferHookExecute {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Submit Investor KYC, the declared profile is reviewed off-chain
+#[repr(C)]
+pub struct SubmitInvestorKYC {
+    pub jurisdiction: [u8; 2],     // ISO 3166-1 alpha-2
+    pub accreditation_tier: u8,    // states::AccreditationTier
+}
+
+impl DataLen for SubmitInvestorKYC {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for SubmitInvestorKYC {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Approve Investor KYC, the kyc_authority's verified profile replaces the declared one
+#[repr(C)]
+pub struct ApproveInvestorKYC {
+    pub expires_at: i64,
+    pub jurisdiction: [u8; 2],
+    pub accreditation_tier: u8,
+}
+
+impl DataLen for ApproveInvestorKYC {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for ApproveInvestorKYC {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Revoke Investor KYC, `reason_code` must be non-zero
+#[repr(C)]
+pub struct RevokeInvestorKYC {
+    pub reason_code: u8,
+}
+
+impl DataLen for RevokeInvestorKYC {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for RevokeInvestorKYC {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Set Token Config Rules, only the first `jurisdiction_count` codes are read
+#[repr(C)]
+pub struct SetTokenConfigRules {
+    pub jurisdiction_mode: u8,         // states::JurisdictionMode
+    pub min_accreditation_tier: u8,    // states::AccreditationTier
+    pub jurisdiction_count: u8,
+    pub jurisdictions: [u8; 2 * MAX_JURISDICTIONS], // same layout as TokenConfig.jurisdictions
+}
+
+impl DataLen for SetTokenConfigRules {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for SetTokenConfigRules {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Update RWA Metadata, `field` is a `states::MetadataUpdate`.
+/// `key` names the additional field and is ignored for the uri.
+#[repr(C)]
+pub struct UpdateRWAMetadata {
+    pub field: u8,
+    pub key: [u8; 32],    // fixed-length, UTF-8 string
+    pub value: [u8; 200], // fixed-length, UTF-8 string
+}
+
+impl DataLen for UpdateRWAMetadata {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for UpdateRWAMetadata {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Freeze Holder, `reason_code` is required
+#[repr(C)]
+pub struct FreezeHolder {
+    pub reason_code: u8,
+}
+
+impl DataLen for FreezeHolder {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for FreezeHolder {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
+
+/// Force Transfer, `reason_code` is required
+#[repr(C)]
+pub struct ForceTransfer {
+    pub amount: u64,
+    pub reason_code: u8,
+}
+
+impl DataLen for ForceTransfer {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl<'a> ZeroCopyTryFrom<'a> for ForceTransfer {
+    fn try_from_bytes(data: &'a [u8]) -> Result<&'a Self, ProgramError> {
+        unsafe { load_ix_data::<Self>(data) }
+    }
+}
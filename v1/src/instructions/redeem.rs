@@ -0,0 +1,253 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        seeds,
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, FeeCollected, FeeKind, Redeemed},
+        instructions::{RWAInstruction, Redeem, ZeroCopyTryFrom},
+        states::{GlobalConfig, RedemptionRequest, RedemptionStatus, TokenConfig},
+        utils::{
+            load_acc_mut_unchecked, load_acc_unchecked, AccountCheck, AssociatedTokenAccount,
+            AssociatedTokenAccountInit, BurnChecked, DataLen, Mint2022Account, ProgramAccount, ProgramAccountInit, SignerAccount,
+            TokenAccount2022, TransferCheckedWithHook, TransferHookAccounts, TOKEN_2022_PROGRAM_ID,
+        },
+    },
+};
+
+/// Accounts required to redeem RWA tokens
+pub struct RedeemAccounts<'a> {
+    pub holder: &'a AccountInfo,        // token owner, pays for the receipt and the fee recipient ATA
+    pub global_config: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub holder_ata: &'a AccountInfo,
+    pub redemption: &'a AccountInfo,
+    pub fee_recipient: &'a AccountInfo,   // fee vault PDA, or the asset's own fee_recipient
+    pub fee_recipient_ata: &'a AccountInfo, // receives the redemption fee
+    pub freeze_authority: &'a AccountInfo, // thaws a fee recipient ATA created on a frozen-by-default mint
+    pub system_program: &'a AccountInfo,
+    pub token_program_2022: &'a AccountInfo,
+    // Forwarded to the KYC transfer hook for the fee transfer
+    pub extra_account_metas: &'a AccountInfo,
+    pub holder_kyc: &'a AccountInfo,
+    pub fee_recipient_kyc: &'a AccountInfo,
+    pub hook_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RedeemAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [holder, global_config, token_config, mint, holder_ata, redemption, fee_recipient, fee_recipient_ata, freeze_authority, system_program, token_program_2022, _associated_token_program, extra_account_metas, holder_kyc, fee_recipient_kyc, hook_program] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(holder)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(token_config)?;
+        Mint2022Account::check(mint)?;
+        if *token_program_2022.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        Ok(Self {
+            holder,
+            global_config,
+            token_config,
+            mint,
+            holder_ata,
+            redemption,
+            fee_recipient,
+            fee_recipient_ata,
+            freeze_authority,
+            system_program,
+            token_program_2022,
+            extra_account_metas,
+            holder_kyc,
+            fee_recipient_kyc,
+            hook_program,
+        })
+    }
+}
+
+/// Instruction wrapper
+pub struct RedeemInstruction<'a> {
+    pub accounts: RedeemAccounts<'a>,
+    pub instruction_datas: &'a Redeem,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for RedeemInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = RedeemAccounts::try_from(accounts)?;
+        let instruction_datas = Redeem::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> RedeemInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::Redeem as u8;
+
+    /// Process the instruction: pay the redemption fee, burn the rest and open a Pending redemption receipt
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        let amount = self.instruction_datas.amount;
+        if amount == 0 {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        // Fee rate is locked in at request time
+        GlobalConfig::check_pda(self.accounts.global_config, program_id)?;
+        let global_config_data = self.accounts.global_config.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+        global_config.check_open()?;
+
+        let token_config_data = self.accounts.token_config.try_borrow_data()?;
+        let token_config: &TokenConfig = unsafe { load_acc_unchecked(&token_config_data)? };
+        if token_config.mint != *self.accounts.mint.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        // The fee comes out of the redeemed amount instead of being minted as new supply
+        let fee = token_config.redemption_fee(global_config, amount);
+        let burned = amount.checked_sub(fee).ok_or(RWAError::InvalidFeeBps)?;
+        let decimals = token_config.decimal;
+        if fee > 0 {
+            token_config.check_fee_recipient(self.accounts.fee_recipient, program_id)?;
+        }
+        let freeze_bump_ref = [token_config.freeze_authority_bump];
+        // The hook reads both configs again during the fee transfer
+        drop(global_config_data);
+        drop(token_config_data);
+
+        let nonce = self.instruction_datas.nonce.to_le_bytes();
+        let (expected_pda, bump) = find_program_address(
+            &[
+                RedemptionRequest::SEED_PREFIX,
+                self.accounts.mint.key().as_ref(),
+                self.accounts.holder.key().as_ref(),
+                &nonce,
+            ],
+            program_id,
+        );
+        if *self.accounts.redemption.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        if fee > 0 {
+            let fee_recipient_ata_is_new = self.accounts.fee_recipient_ata.data_is_empty();
+            AssociatedTokenAccount::init_if_needed(
+                self.accounts.fee_recipient_ata,
+                self.accounts.mint,
+                self.accounts.holder,
+                self.accounts.fee_recipient,
+                self.accounts.system_program,
+                self.accounts.token_program_2022,
+            )?;
+            if fee_recipient_ata_is_new {
+                let freeze_seeds = seeds!(TokenConfig::FREEZE_AUTHORITY_SEED, self.accounts.token_config.key().as_ref(), &freeze_bump_ref);
+                TokenAccount2022::thaw_if_frozen(
+                    self.accounts.fee_recipient_ata,
+                    self.accounts.mint,
+                    self.accounts.freeze_authority,
+                    &[Signer::from(&freeze_seeds)],
+                )?;
+            }
+
+            // Holder signs the transfer, the fee vault is exempt from the receiver KYC check
+            TransferCheckedWithHook {
+                from: self.accounts.holder_ata,
+                mint: self.accounts.mint,
+                to: self.accounts.fee_recipient_ata,
+                authority: self.accounts.holder,
+                amount: fee,
+                decimals,
+                hook: TransferHookAccounts {
+                    extra_account_metas: self.accounts.extra_account_metas,
+                    global_config: self.accounts.global_config,
+                    token_config: self.accounts.token_config,
+                    sender_kyc: self.accounts.holder_kyc,
+                    receiver_kyc: self.accounts.fee_recipient_kyc,
+                    hook_program: self.accounts.hook_program,
+                },
+            }
+            .invoke_signed(&[])?;
+
+            FeeCollected {
+                mint: *self.accounts.mint.key(),
+                fee_ata: *self.accounts.fee_recipient_ata.key(),
+                amount: fee,
+                kind: FeeKind::Redemption as u8,
+            }
+            .emit();
+        }
+
+        // Holder signs the burn, Token-2022 enforces ownership and balance
+        BurnChecked {
+            account: self.accounts.holder_ata,
+            mint: self.accounts.mint,
+            authority: self.accounts.holder,
+            amount: burned,
+            decimals,
+        }
+        .invoke()?;
+
+        // `init`, not `init_if_needed`: a nonce can only be used once
+        let bump_ref = [bump];
+        let seeds = seeds!(
+            RedemptionRequest::SEED_PREFIX,
+            self.accounts.mint.key().as_ref(),
+            self.accounts.holder.key().as_ref(),
+            &nonce,
+            &bump_ref
+        );
+        ProgramAccount::init(self.accounts.holder, self.accounts.redemption, &seeds, RedemptionRequest::LEN)?;
+
+        let mut redemption_data = self.accounts.redemption.try_borrow_mut_data()?;
+        let redemption: &mut RedemptionRequest = unsafe { load_acc_mut_unchecked(&mut redemption_data)? };
+
+        redemption.holder = *self.accounts.holder.key();
+        redemption.mint = *self.accounts.mint.key();
+        redemption.amount = amount;
+        redemption.fee = fee;
+        redemption.nonce = self.instruction_datas.nonce;
+        redemption.requested_at = Clock::get()?.unix_timestamp;
+        redemption.settled_at = 0;
+        redemption.status = RedemptionStatus::Pending as u8;
+        redemption.reason_code = 0;
+        redemption.bump = bump;
+
+        // Keeps CloseTokenConfig from stranding a redemption that may still be minted back,
+        // and MintRWA from issuing into the room a refund would need
+        let mut token_config_data = self.accounts.token_config.try_borrow_mut_data()?;
+        let token_config: &mut TokenConfig = unsafe { load_acc_mut_unchecked(&mut token_config_data)? };
+        token_config.pending_redemptions = token_config.pending_redemptions.saturating_add(1);
+        token_config.pending_redemption_amount = token_config
+            .pending_redemption_amount
+            .checked_add(redemption.burned())
+            .ok_or(RWAError::MaxSupplyExceeded)?;
+
+        Redeemed {
+            mint: redemption.mint,
+            holder: redemption.holder,
+            redemption: *self.accounts.redemption.key(),
+            amount,
+            fee,
+            nonce: redemption.nonce,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
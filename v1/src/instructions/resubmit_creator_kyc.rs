@@ -0,0 +1,96 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, KycResubmitted},
+        instructions::{RWAInstruction, ResubmitCreatorKYC, ZeroCopyTryFrom},
+        states::{CreatorKYC, KycStatus},
+        utils::{load_acc_mut_unchecked, AccountCheck, ProgramAccount, SignerAccount},
+    },
+};
+
+/// Accounts required to put a lapsed creator KYC back in the review queue
+pub struct ResubmitCreatorKYCAccounts<'a> {
+    pub creator: &'a AccountInfo,
+    pub creator_kyc: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ResubmitCreatorKYCAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [creator, creator_kyc] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(creator)?;
+        ProgramAccount::check(creator_kyc)?;
+
+        Ok(Self { creator, creator_kyc })
+    }
+}
+
+/// Instruction wrapper
+pub struct ResubmitCreatorKYCInstruction<'a> {
+    pub accounts: ResubmitCreatorKYCAccounts<'a>,
+    pub instruction_datas: &'a ResubmitCreatorKYC,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for ResubmitCreatorKYCInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = ResubmitCreatorKYCAccounts::try_from(accounts)?;
+        let instruction_datas = ResubmitCreatorKYC::try_from_bytes(data)?;
+
+        Ok(Self { accounts, instruction_datas })
+    }
+}
+
+impl<'a> ResubmitCreatorKYCInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::ResubmitCreatorKYC as u8;
+
+    /// Process the instruction: move an expired record back to Pending with the renewed commitment, keeping its history
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        if self.instruction_datas.commitment == [0u8; 32] {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        let (expected_pda, _) = find_program_address(&[CreatorKYC::SEED_PREFIX, self.accounts.creator.key().as_ref()], program_id);
+        if *self.accounts.creator_kyc.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+
+        let mut creator_kyc_data = self.accounts.creator_kyc.try_borrow_mut_data()?;
+        let creator_kyc: &mut CreatorKYC = unsafe { load_acc_mut_unchecked(&mut creator_kyc_data)? };
+
+        if creator_kyc.effective_status(Clock::get()?.unix_timestamp)? != KycStatus::Expired {
+            return Err(RWAError::InvalidKycStatus.into());
+        }
+
+        creator_kyc.commitment = self.instruction_datas.commitment;
+        creator_kyc.provider = self.instruction_datas.provider;
+        creator_kyc.status = KycStatus::Pending as u8;
+        creator_kyc.reason_code = 0;
+        creator_kyc.decided_at = 0;
+        creator_kyc.expires_at = 0;
+        creator_kyc.submission_count = creator_kyc.submission_count.saturating_add(1);
+
+        KycResubmitted {
+            creator: creator_kyc.wallet,
+            creator_kyc: *self.accounts.creator_kyc.key(),
+            commitment: creator_kyc.commitment,
+            provider: creator_kyc.provider,
+            submission_count: creator_kyc.submission_count,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        seeds,
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, RedemptionSettled},
+        instructions::{RWAInstruction, SettleRedemption, ZeroCopyTryFrom},
+        states::{RedemptionRequest, RedemptionStatus, TokenConfig},
+        utils::{
+            load_acc_mut_unchecked, AccountCheck, Mint2022Account, MintToChecked, ProgramAccount, SignerAccount,
+            TOKEN_2022_PROGRAM_ID,
+        },
+    },
+};
+
+/// Accounts required to settle or reject a redemption
+pub struct SettleRedemptionAccounts<'a> {
+    pub issuer: &'a AccountInfo,          // token config creator
+    pub token_config: &'a AccountInfo,
+    pub redemption: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub holder_ata: &'a AccountInfo,      // refund target on reject
+    pub mint_authority: &'a AccountInfo,
+    pub token_program_2022: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SettleRedemptionAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [issuer, token_config, redemption, mint, holder_ata, mint_authority, token_program_2022] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(issuer)?;
+        ProgramAccount::check(token_config)?;
+        ProgramAccount::check(redemption)?;
+        Mint2022Account::check(mint)?;
+        if *token_program_2022.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        Ok(Self { issuer, token_config, redemption, mint, holder_ata, mint_authority, token_program_2022 })
+    }
+}
+
+/// Instruction wrapper
+pub struct SettleRedemptionInstruction<'a> {
+    pub accounts: SettleRedemptionAccounts<'a>,
+    pub instruction_datas: &'a SettleRedemption,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for SettleRedemptionInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = SettleRedemptionAccounts::try_from(accounts)?;
+        let instruction_datas = SettleRedemption::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> SettleRedemptionInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::SettleRedemption as u8;
+
+    /// Process the instruction: mark a Pending redemption Settled, or Rejected and mint the burned tokens back
+    pub fn process(&mut self, _program_id: &Pubkey) -> ProgramResult {
+        let mut token_config_data = self.accounts.token_config.try_borrow_mut_data()?;
+        let token_config: &mut TokenConfig = unsafe { load_acc_mut_unchecked(&mut token_config_data)? };
+
+        if token_config.creator != *self.accounts.issuer.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+        if token_config.mint != *self.accounts.mint.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        let mut redemption_data = self.accounts.redemption.try_borrow_mut_data()?;
+        let redemption: &mut RedemptionRequest = unsafe { load_acc_mut_unchecked(&mut redemption_data)? };
+
+        if redemption.mint != token_config.mint {
+            return Err(RWAError::InvalidAddress.into());
+        }
+        if redemption.status()? != RedemptionStatus::Pending {
+            return Err(RWAError::RedemptionNotPending.into());
+        }
+
+        let settled = self.instruction_datas.settled()?;
+        let reason_code = self.instruction_datas.reason_code;
+        // Same rule as KYC decisions: only a rejection carries a reason
+        if settled != (reason_code == 0) {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+
+        // Settled or not, the burned amount is no longer held back for a refund
+        token_config.pending_redemptions = token_config.pending_redemptions.saturating_sub(1);
+        token_config.pending_redemption_amount = token_config.pending_redemption_amount.saturating_sub(redemption.burned());
+
+        let bump_ref = [token_config.mint_authority_bump];
+        let seeds = seeds!(TokenConfig::MINT_AUTHORITY_SEED, self.accounts.token_config.key().as_ref(), &bump_ref);
+
+        // Settling only closes the receipt, the fee was paid and the rest burned by Redeem
+        if !settled {
+            // Token-2022 checks the refund ATA belongs to this mint, we check it belongs to the holder
+            let holder_ata_data = self.accounts.holder_ata.try_borrow_data()?;
+            if holder_ata_data.get(32..64) != Some(redemption.holder.as_ref()) {
+                return Err(RWAError::InvalidAddress.into());
+            }
+            drop(holder_ata_data);
+
+            // Always fits while the reservation holds, checked anyway so a refund can never breach the cap
+            let supply = Mint2022Account::supply(self.accounts.mint)?;
+            token_config.check_max_supply(supply, redemption.burned())?;
+
+            MintToChecked {
+                mint: self.accounts.mint,
+                account: self.accounts.holder_ata,
+                mint_authority: self.accounts.mint_authority,
+                amount: redemption.burned(),
+                decimals: token_config.decimal,
+            }
+            .invoke_signed(&[Signer::from(&seeds)])?;
+        }
+
+        let status = if settled { RedemptionStatus::Settled } else { RedemptionStatus::Rejected };
+        redemption.status = status as u8;
+        redemption.reason_code = reason_code;
+        redemption.settled_at = Clock::get()?.unix_timestamp;
+
+        RedemptionSettled {
+            redemption: *self.accounts.redemption.key(),
+            settled_at: redemption.settled_at,
+            status: redemption.status,
+            reason_code,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        seeds,
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, InvestorAccountThawed},
+        instructions::RWAInstruction,
+        states::{HolderStatus, InvestorKYC, TokenConfig},
+        utils::{
+            load_acc_unchecked, AccountCheck, Mint2022Account, ProgramAccount, SignerAccount, TokenAccount2022,
+            TOKEN_2022_PROGRAM_ID,
+        },
+    },
+};
+
+/// Accounts required to thaw a holder's token account
+pub struct ThawInvestorAccountAccounts<'a> {
+    pub signer: &'a AccountInfo,           // anyone, the KYC record is what gates the thaw
+    pub token_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub token_account: &'a AccountInfo,
+    pub investor_kyc: &'a AccountInfo,     // KYC record of the token account owner
+    pub holder_status: &'a AccountInfo,    // the owner's legal hold record, may not exist
+    pub freeze_authority: &'a AccountInfo,
+    pub token_program_2022: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ThawInvestorAccountAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [signer, token_config, mint, token_account, investor_kyc, holder_status, freeze_authority, token_program_2022] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(signer)?;
+        ProgramAccount::check(token_config)?;
+        Mint2022Account::check(mint)?;
+        if *token_program_2022.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        Ok(Self { signer, token_config, mint, token_account, investor_kyc, holder_status, freeze_authority, token_program_2022 })
+    }
+}
+
+/// Instruction wrapper
+pub struct ThawInvestorAccountInstruction<'a> {
+    pub accounts: ThawInvestorAccountAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for ThawInvestorAccountInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((_data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = ThawInvestorAccountAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> ThawInvestorAccountInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::ThawInvestorAccount as u8;
+
+    /// Process the instruction: thaw a token account whose owner is eligible to hold the asset
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        let token_config_data = self.accounts.token_config.try_borrow_data()?;
+        let token_config: &TokenConfig = unsafe { load_acc_unchecked(&token_config_data)? };
+
+        if token_config.mint == Pubkey::default() || token_config.mint != *self.accounts.mint.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        // Token-2022 checks the account belongs to the mint, we check its owner
+        let holder = TokenAccount2022::owner(self.accounts.token_account)?;
+        InvestorKYC::check_eligible(self.accounts.investor_kyc, &holder, token_config, program_id)?;
+
+        // A legal hold is only lifted through ThawHolder
+        let (expected_pda, _) = find_program_address(
+            &[HolderStatus::SEED_PREFIX, token_config.mint.as_ref(), holder.as_ref()],
+            program_id,
+        );
+        if *self.accounts.holder_status.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+        if self.accounts.holder_status.is_owned_by(program_id) {
+            let holder_status_data = self.accounts.holder_status.try_borrow_data()?;
+            let holder_status: &HolderStatus = unsafe { load_acc_unchecked(&holder_status_data)? };
+            if holder_status.frozen {
+                return Err(RWAError::HolderFrozen.into());
+            }
+        }
+
+        let bump_ref = [token_config.freeze_authority_bump];
+        let seeds = seeds!(TokenConfig::FREEZE_AUTHORITY_SEED, self.accounts.token_config.key().as_ref(), &bump_ref);
+        TokenAccount2022::thaw_if_frozen(
+            self.accounts.token_account,
+            self.accounts.mint,
+            self.accounts.freeze_authority,
+            &[Signer::from(&seeds)],
+        )?;
+
+        InvestorAccountThawed {
+            mint: token_config.mint,
+            holder,
+            token_account: *self.accounts.token_account.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
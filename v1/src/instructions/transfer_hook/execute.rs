@@ -0,0 +1,109 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        instructions::{TransferHookExecute, ZeroCopyTryFrom},
+        states::{GlobalConfig, InvestorKYC, TokenConfig, FEE_VAULT_SEED},
+        utils::{
+            load_acc_unchecked, AccountCheck, ExtraAccountMetas, Mint2022Account, ProgramAccount,
+            TokenAccount2022, EXECUTE_DISCRIMINATOR,
+        },
+    },
+};
+
+/// Accounts Token-2022 passes to the hook, in the order set by `ExtraAccountMetas`
+pub struct TransferHookExecuteAccounts<'a> {
+    pub source: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub destination: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub extra_account_metas: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+    pub sender_kyc: &'a AccountInfo,
+    pub receiver_kyc: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for TransferHookExecuteAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [source, mint, destination, authority, extra_account_metas, global_config, token_config, sender_kyc, receiver_kyc, ..] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        Mint2022Account::check(mint)?;
+        ProgramAccount::check(global_config)?;
+        ProgramAccount::check(token_config)?;
+
+        Ok(Self { source, mint, destination, authority, extra_account_metas, global_config, token_config, sender_kyc, receiver_kyc })
+    }
+}
+
+/// Instruction wrapper
+pub struct TransferHookExecuteInstruction<'a> {
+    pub accounts: TransferHookExecuteAccounts<'a>,
+    pub instruction_datas: &'a TransferHookExecute,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for TransferHookExecuteInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = TransferHookExecuteAccounts::try_from(accounts)?;
+        let instruction_datas = TransferHookExecute::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> TransferHookExecuteInstruction<'a> {
+    pub const DISCRIMINATOR: [u8; 8] = EXECUTE_DISCRIMINATOR;
+
+    /// Process the instruction: allow the transfer only between eligible investors of an active asset
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        // Only Token-2022 sets this flag, a direct call can't fake a transfer
+        if !TokenAccount2022::is_transferring(self.accounts.source)? {
+            return Err(RWAError::NotTransferring.into());
+        }
+
+        ExtraAccountMetas::check_pda(self.accounts.extra_account_metas, self.accounts.mint.key(), program_id)?;
+        GlobalConfig::check_open_account(self.accounts.global_config, program_id)?;
+
+        let token_config_data = self.accounts.token_config.try_borrow_data()?;
+        let token_config: &TokenConfig = unsafe { load_acc_unchecked(&token_config_data)? };
+        if token_config.mint != *self.accounts.mint.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+        if !token_config.active {
+            return Err(RWAError::TokenConfigNotActive.into());
+        }
+
+        // Protocol fees leave the vault without the vault itself holding a KYC record.
+        // The asset rules only gate who may receive, a holder can always sell out.
+        // A ForceTransfer signed by the permanent delegate moves tokens off a revoked holder too.
+        let sender = TokenAccount2022::owner(self.accounts.source)?;
+        let forced = token_config.permanent_delegate != Pubkey::default()
+            && *self.accounts.authority.key() == token_config.permanent_delegate;
+        let (fee_vault, _) = find_program_address(&[FEE_VAULT_SEED], program_id);
+        if !forced && sender != fee_vault {
+            InvestorKYC::check_approved(self.accounts.sender_kyc, &sender, program_id)?;
+        }
+
+        // Redemption fees are paid into the vault the same way
+        let receiver = TokenAccount2022::owner(self.accounts.destination)?;
+        if receiver != fee_vault {
+            InvestorKYC::check_eligible(self.accounts.receiver_kyc, &receiver, token_config, program_id)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        states::TokenConfig,
+        utils::{
+            load_acc_unchecked, AccountCheck, ExtraAccountMetas, Mint2022Account, ProgramAccount,
+            SignerAccount, INITIALIZE_EXTRA_ACCOUNT_METAS_DISCRIMINATOR,
+        },
+    },
+};
+
+/// Interface accounts, plus the asset's TokenConfig the metas point at
+pub struct InitializeExtraAccountMetasAccounts<'a> {
+    pub extra_account_metas: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub authority: &'a AccountInfo,       // token config creator, pays for the account
+    pub system_program: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for InitializeExtraAccountMetasAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [extra_account_metas, mint, authority, system_program, token_config] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        Mint2022Account::check(mint)?;
+        SignerAccount::check(authority)?;
+        ProgramAccount::check(token_config)?;
+
+        Ok(Self { extra_account_metas, mint, authority, system_program, token_config })
+    }
+}
+
+/// Instruction wrapper. CreateRWA already writes the metas, this covers the interface
+/// for tooling and mints whose validation account was closed.
+pub struct InitializeExtraAccountMetasInstruction<'a> {
+    pub accounts: InitializeExtraAccountMetasAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for InitializeExtraAccountMetasInstruction<'a> {
+    type Error = ProgramError;
+
+    // The client supplied metas are ignored, the program decides what it needs
+    fn try_from((_data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = InitializeExtraAccountMetasAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> InitializeExtraAccountMetasInstruction<'a> {
+    pub const DISCRIMINATOR: [u8; 8] = INITIALIZE_EXTRA_ACCOUNT_METAS_DISCRIMINATOR;
+
+    /// Process the instruction: create the hook validation account of an RWA mint
+    pub fn process(&mut self, program_id: &Pubkey) -> ProgramResult {
+        let token_config_data = self.accounts.token_config.try_borrow_data()?;
+        let token_config: &TokenConfig = unsafe { load_acc_unchecked(&token_config_data)? };
+
+        if token_config.mint != *self.accounts.mint.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+        if token_config.creator != *self.accounts.authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+
+        ExtraAccountMetas::init(
+            self.accounts.authority,
+            self.accounts.extra_account_metas,
+            self.accounts.mint.key(),
+            self.accounts.token_config.key(),
+            program_id,
+        )
+    }
+}
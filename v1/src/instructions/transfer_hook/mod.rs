@@ -0,0 +1,5 @@
+pub mod execute;
+pub use execute::*;
+
+pub mod initialize_extra_account_metas;
+pub use initialize_extra_account_metas::*;
@@ -0,0 +1,124 @@
+use {
+    core::convert::TryFrom,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        seeds,
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        events::{Event, RwaMetadataUpdated},
+        instructions::{RWAInstruction, UpdateRWAMetadata, ZeroCopyTryFrom},
+        states::{MetadataUpdate, TokenConfig},
+        utils::{
+            fund_rent_exemption, load_acc_unchecked, trim_nul, AccountCheck, MetadataField, ProgramAccount,
+            SignerAccount, UpdateTokenMetadataField, TOKEN_2022_PROGRAM_ID,
+        },
+    },
+};
+
+/// Accounts required to edit an RWA's on-mint metadata
+pub struct UpdateRWAMetadataAccounts<'a> {
+    pub update_authority: &'a AccountInfo, // TokenConfig.metadata_update_authority, pays for any growth
+    pub token_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub mint_authority: &'a AccountInfo,   // holds the Token-2022 update authority
+    pub system_program: &'a AccountInfo,
+    pub token_program_2022: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for UpdateRWAMetadataAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(infos: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [update_authority, token_config, mint, mint_authority, system_program, token_program_2022] = infos else {
+            return Err(RWAError::NotEnoughAccountKeys.into());
+        };
+        SignerAccount::check(update_authority)?;
+        ProgramAccount::check(token_config)?;
+        if *token_program_2022.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(RWAError::InvalidAddress.into());
+        }
+
+        Ok(Self { update_authority, token_config, mint, mint_authority, system_program, token_program_2022 })
+    }
+}
+
+/// Instruction wrapper
+pub struct UpdateRWAMetadataInstruction<'a> {
+    pub accounts: UpdateRWAMetadataAccounts<'a>,
+    pub instruction_datas: &'a UpdateRWAMetadata,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for UpdateRWAMetadataInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = UpdateRWAMetadataAccounts::try_from(accounts)?;
+        let instruction_datas = UpdateRWAMetadata::try_from_bytes(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_datas,
+        })
+    }
+}
+
+impl<'a> UpdateRWAMetadataInstruction<'a> {
+    pub const DISCRIMINATOR: u8 = RWAInstruction::UpdateRWAMetadata as u8;
+
+    /// Process the instruction: rewrite the uri or an additional field of a mutable RWA's metadata
+    pub fn process(&mut self, _program_id: &Pubkey) -> ProgramResult {
+        let token_config_data = self.accounts.token_config.try_borrow_data()?;
+        let token_config: &TokenConfig = unsafe { load_acc_unchecked(&token_config_data)? };
+
+        if token_config.mint == Pubkey::default() || token_config.mint != *self.accounts.mint.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+        if token_config.metadata_update_authority != *self.accounts.update_authority.key() {
+            return Err(RWAError::Unauthorized.into());
+        }
+        if token_config.metadata_immutable {
+            return Err(RWAError::MetadataImmutable.into());
+        }
+
+        let update = MetadataUpdate::try_from(self.instruction_datas.field)?;
+        let key = trim_nul(&self.instruction_datas.key);
+        let field = match update {
+            MetadataUpdate::Uri => MetadataField::Uri,
+            MetadataUpdate::Field => MetadataField::Key(key),
+        };
+
+        // Token-2022 rejects a signer that isn't the mint authority PDA the metadata was created with
+        let bump_ref = [token_config.mint_authority_bump];
+        let seeds = seeds!(TokenConfig::MINT_AUTHORITY_SEED, self.accounts.token_config.key().as_ref(), &bump_ref);
+
+        UpdateTokenMetadataField {
+            mint: self.accounts.mint,
+            update_authority: self.accounts.mint_authority,
+            field,
+            value: trim_nul(&self.instruction_datas.value),
+        }
+        .invoke_signed(&[Signer::from(&seeds)])?;
+
+        // A longer value grows the mint
+        fund_rent_exemption(self.accounts.mint, self.accounts.update_authority)?;
+
+        let mut event_key = [0u8; 32];
+        if update == MetadataUpdate::Field {
+            event_key = self.instruction_datas.key;
+        }
+        RwaMetadataUpdated {
+            mint: token_config.mint,
+            update_authority: token_config.metadata_update_authority,
+            field: update as u8,
+            key: event_key,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
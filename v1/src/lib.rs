@@ -9,6 +9,7 @@ extern crate std;
 
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod states;
 pub mod utils;
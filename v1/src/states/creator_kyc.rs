@@ -1,20 +1,115 @@
 use {
-    pinocchio::pubkey::Pubkey,
-    crate::utils::DataLen,
+    pinocchio::{program_error::ProgramError, pubkey::Pubkey, ProgramResult},
+    crate::{errors::RWAError, utils::{sha256, DataLen}},
 };
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct CreatorKYC {
-    pub name: [u8; 32],      // max 32 bytes - multiple of 8
-    pub email_id: [u8; 32],  // max 32 bytes
+    // sha256(dossier || salt), the dossier itself stays with the provider
+    pub commitment: [u8; 32],
+    pub provider: [u8; 16],  // KYC provider identifier, fixed-length ASCII
     pub wallet: Pubkey,      // wallet identity of creator
-    pub verified: bool,      // whether KYC is approved
+    pub decided_at: i64,     // unix timestamp of the last kyc_authority decision, 0 until reviewed
+    pub expires_at: i64,     // approval lapses at this unix timestamp, 0 until approved
+    // History, kept across resubmissions
+    pub submission_count: u16,
+    pub approval_count: u16,
+    pub status: u8,          // KycStatus
+    pub reason_code: u8,     // set on reject/revoke, 0 otherwise
     pub bump:u8,
 }
 
+impl CreatorKYC {
+    pub const SEED_PREFIX: &'static [u8] = b"creator-kyc";
+
+    pub fn status(&self) -> Result<KycStatus, ProgramError> {
+        KycStatus::try_from(self.status)
+    }
+
+    /// True if `dossier` and `salt` are the preimage of the stored commitment, for auditors
+    pub fn verify_evidence(&self, dossier: &[u8], salt: &[u8; 32]) -> bool {
+        sha256(&[dossier, salt]) == self.commitment
+    }
+
+    /// An approval past `expires_at` reads as Expired without anyone having to flip the status
+    pub fn effective_status(&self, now: i64) -> Result<KycStatus, ProgramError> {
+        match self.status()? {
+            KycStatus::Approved if now >= self.expires_at => Ok(KycStatus::Expired),
+            status => Ok(status),
+        }
+    }
+
+    /// Fails with KycExpired or KycNotApproved unless the creator may act right now
+    pub fn check_approved(&self, now: i64) -> ProgramResult {
+        match self.effective_status(now)? {
+            KycStatus::Approved => Ok(()),
+            KycStatus::Expired => Err(RWAError::KycExpired.into()),
+            _ => Err(RWAError::KycNotApproved.into()),
+        }
+    }
+}
+
 impl DataLen for CreatorKYC {
      const LEN: usize = core::mem::size_of::<CreatorKYC>(); 
 }
 
+/// Lifecycle of a KYC record
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KycStatus {
+    Pending = 0,
+    Approved = 1,
+    Rejected = 2,
+    Revoked = 3,
+    Expired = 4,
+}
+
+impl TryFrom<u8> for KycStatus {
+    type Error = ProgramError;
 
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(KycStatus::Pending),
+            1 => Ok(KycStatus::Approved),
+            2 => Ok(KycStatus::Rejected),
+            3 => Ok(KycStatus::Revoked),
+            4 => Ok(KycStatus::Expired),
+            _ => Err(RWAError::InvalidAccountData.into()),
+        }
+    }
+}
+
+/// What the kyc_authority decided about a record
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KycDecision {
+    Approve = 0,
+    Reject = 1,
+    Revoke = 2,
+}
+
+impl TryFrom<u8> for KycDecision {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(KycDecision::Approve),
+            1 => Ok(KycDecision::Reject),
+            2 => Ok(KycDecision::Revoke),
+            _ => Err(RWAError::InvalidInstructionData.into()),
+        }
+    }
+}
+
+impl KycDecision {
+    /// Status the record moves to, or None if the decision doesn't apply to `from`
+    pub fn apply(self, from: KycStatus) -> Option<KycStatus> {
+        match (self, from) {
+            (KycDecision::Approve, KycStatus::Pending) => Some(KycStatus::Approved),
+            (KycDecision::Reject, KycStatus::Pending) => Some(KycStatus::Rejected),
+            (KycDecision::Revoke, KycStatus::Approved) => Some(KycStatus::Revoked),
+            _ => None,
+        }
+    }
+}
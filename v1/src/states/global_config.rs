@@ -1,8 +1,14 @@
 use {
-    pinocchio::pubkey::Pubkey,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
     crate::{
-        instructions::RWAInstruction::InitGlobalConfig,
-        utils::DataLen,
+        errors::RWAError,
+        utils::{load_acc_unchecked, DataLen},
     },
 };
 
@@ -12,13 +18,154 @@ use {
 pub struct GlobalConfig {
     pub config_authority: Pubkey,
     pub kyc_authority: Pubkey,
+    // Proposed replacements, all zeroes while no rotation is in flight
+    pub pending_config_authority: Pubkey,
+    pub pending_kyc_authority: Pubkey,
     pub active: bool,
     pub open_time: u64,
     pub max_decimal: u8,
-    pub fees_bps: u8,  
+    pub fees_bps: u16,  // default rate for every asset, at most MAX_FEES_BPS
     pub bump: u8,
+    // Legal holds, starts out as the config authority
+    pub compliance_authority: Pubkey,
+    pub pending_compliance_authority: Pubkey,
+    // Forced transfers through the permanent delegate, starts out as the config authority
+    pub transfer_agent_authority: Pubkey,
+    pub pending_transfer_agent_authority: Pubkey,
+}
+
+
+/// 100% expressed in basis points
+pub const MAX_FEES_BPS: u16 = 10_000;
+/// Program policy, Token-2022 itself takes any u8. A u64 amount is below 1.9e19 base units,
+/// so past 18 decimals a mint couldn't represent even two whole tokens.
+pub const MAX_DECIMALS_POLICY: u8 = 18;
+
+/// Seed of the program PDA that owns the fee vault ATAs, one ATA per mint
+pub const FEE_VAULT_SEED: &[u8] = b"fee-vault";
+
+/// `bps` basis points of `amount`, rounded down in favour of the payer
+pub fn bps_fee(amount: u64, bps: u16) -> u64 {
+    (amount as u128 * bps as u128 / MAX_FEES_BPS as u128) as u64
+}
+
+/// Which GlobalConfig authority a propose/accept/cancel instruction targets
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AuthorityKind {
+    Config = 0,
+    Kyc = 1,
+    Compliance = 2,
+    TransferAgent = 3,
+}
+
+impl TryFrom<u8> for AuthorityKind {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(AuthorityKind::Config),
+            1 => Ok(AuthorityKind::Kyc),
+            2 => Ok(AuthorityKind::Compliance),
+            3 => Ok(AuthorityKind::TransferAgent),
+            _ => Err(RWAError::InvalidInstructionData.into()),
+        }
+    }
 }
 
+impl GlobalConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"global-config";
+
+    /// Circuit breaker: fails while the program is paused or before `open_time`
+    pub fn check_open(&self) -> ProgramResult {
+        if !self.active {
+            return Err(RWAError::ProgramPaused.into());
+        }
+        // A clock before the epoch is treated as "not open yet"
+        let now = Clock::get()?.unix_timestamp;
+        if now < 0 || (now as u64) < self.open_time {
+            return Err(RWAError::NotYetOpen.into());
+        }
+        Ok(())
+    }
+
+    /// Loads the GlobalConfig PDA passed as `account` and runs `check_open` on it
+    pub fn check_open_account(account: &AccountInfo, program_id: &Pubkey) -> ProgramResult {
+        Self::check_pda(account, program_id)?;
+        let global_config_data = account.try_borrow_data()?;
+        let global_config: &GlobalConfig = unsafe { load_acc_unchecked(&global_config_data)? };
+        global_config.check_open()
+    }
+
+    pub fn check_pda(account: &AccountInfo, program_id: &Pubkey) -> ProgramResult {
+        let (expected_pda, _) = find_program_address(&[Self::SEED_PREFIX], program_id);
+        if *account.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+        Ok(())
+    }
+
+    /// Checks `account` is the fee vault PDA and returns its bump
+    pub fn fee_vault_bump(account: &AccountInfo, program_id: &Pubkey) -> Result<u8, ProgramError> {
+        let (expected_pda, bump) = find_program_address(&[FEE_VAULT_SEED], program_id);
+        if *account.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+        Ok(bump)
+    }
+
+    /// Legal holds can be placed by the config authority or the dedicated compliance role
+    pub fn check_compliance(&self, authority: &Pubkey) -> ProgramResult {
+        if *authority != self.config_authority && *authority != self.compliance_authority {
+            return Err(RWAError::Unauthorized.into());
+        }
+        Ok(())
+    }
+
+    /// Unlike legal holds, forced transfers are reserved to the transfer agent alone
+    pub fn check_transfer_agent(&self, authority: &Pubkey) -> ProgramResult {
+        if *authority != self.transfer_agent_authority {
+            return Err(RWAError::Unauthorized.into());
+        }
+        Ok(())
+    }
+
+    pub fn authority(&self, kind: AuthorityKind) -> &Pubkey {
+        match kind {
+            AuthorityKind::Config => &self.config_authority,
+            AuthorityKind::Kyc => &self.kyc_authority,
+            AuthorityKind::Compliance => &self.compliance_authority,
+            AuthorityKind::TransferAgent => &self.transfer_agent_authority,
+        }
+    }
+
+    pub fn pending_authority(&self, kind: AuthorityKind) -> &Pubkey {
+        match kind {
+            AuthorityKind::Config => &self.pending_config_authority,
+            AuthorityKind::Kyc => &self.pending_kyc_authority,
+            AuthorityKind::Compliance => &self.pending_compliance_authority,
+            AuthorityKind::TransferAgent => &self.pending_transfer_agent_authority,
+        }
+    }
+
+    pub fn set_authority(&mut self, kind: AuthorityKind, authority: Pubkey) {
+        match kind {
+            AuthorityKind::Config => self.config_authority = authority,
+            AuthorityKind::Kyc => self.kyc_authority = authority,
+            AuthorityKind::Compliance => self.compliance_authority = authority,
+            AuthorityKind::TransferAgent => self.transfer_agent_authority = authority,
+        }
+    }
+
+    pub fn set_pending_authority(&mut self, kind: AuthorityKind, authority: Pubkey) {
+        match kind {
+            AuthorityKind::Config => self.pending_config_authority = authority,
+            AuthorityKind::Kyc => self.pending_kyc_authority = authority,
+            AuthorityKind::Compliance => self.pending_compliance_authority = authority,
+            AuthorityKind::TransferAgent => self.pending_transfer_agent_authority = authority,
+        }
+    }
+}
 
 impl DataLen for GlobalConfig {
 const LEN: usize = core::mem::size_of::<GlobalConfig>();
@@ -0,0 +1,28 @@
+use {
+    pinocchio::pubkey::Pubkey,
+    crate::utils::DataLen,
+};
+
+/// Legal hold record of one holder on one mint, created by the first FreezeHolder
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankAccount)]
+pub struct HolderStatus {
+    pub mint: Pubkey,
+    pub holder: Pubkey,       // wallet owning the frozen token accounts
+    pub frozen_by: Pubkey,    // authority behind the last freeze
+    pub frozen_at: i64,       // unix timestamp of the last freeze
+    pub thawed_at: i64,       // unix timestamp of the last thaw, 0 until thawed
+    pub freeze_count: u16,    // holds placed so far, kept across thaws
+    pub frozen_accounts: u16, // token accounts frozen under the current hold, it lifts when this reaches 0
+    pub frozen: bool,         // a hold is in force
+    pub reason_code: u8,      // set while frozen, 0 otherwise
+    pub bump: u8,
+}
+
+impl HolderStatus {
+    pub const SEED_PREFIX: &'static [u8] = b"holder-status";
+}
+
+impl DataLen for HolderStatus {
+    const LEN: usize = core::mem::size_of::<HolderStatus>();
+}
@@ -0,0 +1,105 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        states::{KycStatus, TokenConfig},
+        utils::{load_acc_unchecked, DataLen},
+        ID,
+    },
+};
+
+/// KYC record of an investor wallet, checked by MintRWA and by the transfer hook on both ends of a transfer
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankAccount)]
+pub struct InvestorKYC {
+    pub wallet: Pubkey,
+    pub decided_at: i64,          // unix timestamp of the last kyc_authority decision, 0 until reviewed
+    pub expires_at: i64,          // approval lapses at this unix timestamp
+    pub jurisdiction: [u8; 2],    // ISO 3166-1 alpha-2 country code, e.g. b"US"
+    pub accreditation_tier: u8,   // AccreditationTier
+    pub status: u8,               // KycStatus
+    pub reason_code: u8,          // set on revoke, 0 otherwise
+    pub bump: u8,
+}
+
+impl InvestorKYC {
+    pub const SEED_PREFIX: &'static [u8] = b"investor-kyc";
+
+    pub fn status(&self) -> Result<KycStatus, ProgramError> {
+        KycStatus::try_from(self.status)
+    }
+
+    pub fn accreditation_tier(&self) -> Result<AccreditationTier, ProgramError> {
+        AccreditationTier::try_from(self.accreditation_tier)
+    }
+
+    /// Checks `account` is the KYC PDA of `wallet` and that it is Approved.
+    /// A wallet that never went through KYC has no account and is not approved either.
+    pub fn check_approved(account: &AccountInfo, wallet: &Pubkey, program_id: &Pubkey) -> ProgramResult {
+        let (expected_pda, _) = find_program_address(&[Self::SEED_PREFIX, wallet.as_ref()], program_id);
+        if *account.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+        if !account.is_owned_by(&ID) {
+            return Err(RWAError::InvestorKycNotApproved.into());
+        }
+
+        let investor_kyc_data = account.try_borrow_data()?;
+        let investor_kyc: &InvestorKYC = unsafe { load_acc_unchecked(&investor_kyc_data)? };
+        if investor_kyc.status()? != KycStatus::Approved {
+            return Err(RWAError::InvestorKycNotApproved.into());
+        }
+        if Clock::get()?.unix_timestamp >= investor_kyc.expires_at {
+            return Err(RWAError::KycExpired.into());
+        }
+        Ok(())
+    }
+
+    /// `check_approved`, plus the asset's jurisdiction and accreditation rules
+    pub fn check_eligible(
+        account: &AccountInfo,
+        wallet: &Pubkey,
+        token_config: &TokenConfig,
+        program_id: &Pubkey,
+    ) -> ProgramResult {
+        Self::check_approved(account, wallet, program_id)?;
+
+        let investor_kyc_data = account.try_borrow_data()?;
+        let investor_kyc: &InvestorKYC = unsafe { load_acc_unchecked(&investor_kyc_data)? };
+        token_config.check_eligible(investor_kyc)
+    }
+}
+
+impl DataLen for InvestorKYC {
+    const LEN: usize = core::mem::size_of::<InvestorKYC>();
+}
+
+/// Investor classes, ordered from least to most sophisticated so a minimum tier is a plain comparison
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum AccreditationTier {
+    Retail = 0,
+    Accredited = 1,
+    Qualified = 2,
+    Institutional = 3,
+}
+
+impl TryFrom<u8> for AccreditationTier {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(AccreditationTier::Retail),
+            1 => Ok(AccreditationTier::Accredited),
+            2 => Ok(AccreditationTier::Qualified),
+            3 => Ok(AccreditationTier::Institutional),
+            _ => Err(RWAError::InvalidInstructionData.into()),
+        }
+    }
+}
@@ -2,8 +2,13 @@ pub mod global_config;
 pub mod rwa;
 pub mod token_config;
 pub mod creator_kyc;
+pub mod redemption_request;
+pub mod investor_kyc;
+pub mod holder_status;
 
 pub use global_config::*;
-pub use rwa::*;
 pub use token_config::*;
-pub use creator_kyc::*;
\ No newline at end of file
+pub use creator_kyc::*;
+pub use redemption_request::*;
+pub use investor_kyc::*;
+pub use holder_status::*;
@@ -0,0 +1,59 @@
+use {
+    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+    crate::{errors::RWAError, utils::DataLen},
+};
+
+/// Receipt of burned RWA tokens, reconciled off-chain by the custody partner
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, shank::ShankAccount)]
+pub struct RedemptionRequest {
+    pub holder: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,         // redeemed amount, `amount - fee` of it was burned
+    pub fee: u64,            // paid to the fee recipient out of `amount` at request time, kept on reject
+    pub nonce: u64,          // client chosen, lets a holder have several requests in flight
+    pub requested_at: i64,
+    pub settled_at: i64,     // 0 while Pending
+    pub status: u8,          // RedemptionStatus
+    pub reason_code: u8,     // set on reject, 0 otherwise
+    pub bump: u8,
+}
+
+impl RedemptionRequest {
+    pub const SEED_PREFIX: &'static [u8] = b"redemption";
+
+    pub fn status(&self) -> Result<RedemptionStatus, ProgramError> {
+        RedemptionStatus::try_from(self.status)
+    }
+
+    /// What was burned, and what a rejection mints back
+    pub fn burned(&self) -> u64 {
+        self.amount.saturating_sub(self.fee)
+    }
+}
+
+impl DataLen for RedemptionRequest {
+    const LEN: usize = core::mem::size_of::<RedemptionRequest>();
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RedemptionStatus {
+    Pending = 0,
+    Settled = 1,
+    // The burned amount was minted back to the holder, the fee is not refunded
+    Rejected = 2,
+}
+
+impl TryFrom<u8> for RedemptionStatus {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(RedemptionStatus::Pending),
+            1 => Ok(RedemptionStatus::Settled),
+            2 => Ok(RedemptionStatus::Rejected),
+            _ => Err(RWAError::InvalidAccountData.into()),
+        }
+    }
+}
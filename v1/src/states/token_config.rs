@@ -1,8 +1,15 @@
 use {
-    pinocchio::pubkey::Pubkey,
-    crate::utils::DataLen,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult},
+    crate::{
+        errors::RWAError,
+        states::{bps_fee, GlobalConfig, InvestorKYC},
+        utils::DataLen,
+    },
 };
 
+/// Fee override sentinel, the asset falls back to `GlobalConfig.fees_bps`
+pub const FEE_BPS_UNSET: u16 = u16::MAX;
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, shank::ShankAccount)]
 pub struct TokenConfig {
@@ -14,10 +21,229 @@ pub struct TokenConfig {
     pub description: [u8; 128],  // fixed max length (128 chars)
     pub asset_type: [u8; 8],          // e.g. gold, real_estate or some sort of physical asset
     pub audit_cid: [u8; 32],     // compact audit link (like IPFS CID)
-    pub active: bool,            // 1 byte
+    pub active: bool,            // 1 byte, mirrors status == Active
+    pub status: u8,              // TokenConfigStatus
+    pub verified_by: Pubkey,     // config authority behind the last review
+    pub verified_at: i64,        // unix timestamp of the last review, 0 until reviewed
+    pub max_supply: u64,         // cap on total supply fixed by CreateRWA, fee units included since every token is a claim on the asset
+    pub mint_authority_bump: u8, // bump of the MINT_AUTHORITY_SEED PDA
+    // Per-asset fee overrides in bps, FEE_BPS_UNSET falls back to the global rate
+    pub issuance_fee_bps: u16,
+    pub redemption_fee_bps: u16,
+    pub transfer_fee_bps: u16,      // read once by CreateRWA for the TransferFeeConfig extension, frozen afterwards, FEE_BPS_UNSET means no fee
+    pub fee_recipient: Pubkey,   // owner of the fee ATAs, default means the program fee vault
+    // Investor eligibility, checked on issuance and on every transfer
+    pub jurisdiction_mode: u8,   // JurisdictionMode
+    pub jurisdiction_count: u8,  // used entries of `jurisdictions`
+    // MAX_JURISDICTIONS 2-letter codes back to back, shank only takes a flat array with a literal length
+    pub jurisdictions: [u8; 32],
+    pub min_accreditation_tier: u8, // AccreditationTier
+    pub pending_redemptions: u32, // open RedemptionRequests, their tokens are burned but may be minted back
+    pub pending_redemption_amount: u64, // burned by those requests, kept free under max_supply for a refund
+    // Who may edit the on-mint TokenMetadata, the Token-2022 update authority itself is the mint authority PDA
+    pub metadata_update_authority: Pubkey,
+    pub metadata_immutable: bool, // one-way, set by MakeImmutable
+    pub freeze_authority_bump: u8, // bump of the FREEZE_AUTHORITY_SEED PDA
+    // PermanentDelegate PDA behind ForceTransfer, default when CreateRWA didn't set the extension
+    pub permanent_delegate: Pubkey,
+    pub permanent_delegate_bump: u8,
+}
+
+/// Size of the per-asset jurisdiction list
+pub const MAX_JURISDICTIONS: usize = 16;
+const _: () = assert!(2 * MAX_JURISDICTIONS == 32, "resize TokenConfig.jurisdictions");
+
+/// How `TokenConfig.jurisdictions` is applied
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JurisdictionMode {
+    // No jurisdiction restriction
+    Open = 0,
+    // Only the listed jurisdictions may hold the asset
+    Allow = 1,
+    // Every jurisdiction but the listed ones may hold the asset
+    Deny = 2,
+}
+
+impl TryFrom<u8> for JurisdictionMode {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(JurisdictionMode::Open),
+            1 => Ok(JurisdictionMode::Allow),
+            2 => Ok(JurisdictionMode::Deny),
+            _ => Err(RWAError::InvalidInstructionData.into()),
+        }
+    }
+}
+
+/// Which part of the on-mint TokenMetadata UpdateRWAMetadata edits
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MetadataUpdate {
+    Uri = 0,
+    // Additional field, added if the key is new
+    Field = 1,
+}
+
+impl TryFrom<u8> for MetadataUpdate {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MetadataUpdate::Uri),
+            1 => Ok(MetadataUpdate::Field),
+            _ => Err(RWAError::InvalidInstructionData.into()),
+        }
+    }
 }
 
 impl DataLen for TokenConfig {
     const LEN: usize = core::mem::size_of::<TokenConfig>();
-    // = 203 bytes
+}
+
+impl TokenConfig {
+    /// Program PDA `[MINT_AUTHORITY_SEED, token_config]` that owns the mint authority
+    pub const MINT_AUTHORITY_SEED: &'static [u8] = b"mint-authority";
+    /// Program PDA `[FREEZE_AUTHORITY_SEED, token_config]` that freezes and thaws holder accounts
+    pub const FREEZE_AUTHORITY_SEED: &'static [u8] = b"freeze-authority";
+    /// Program PDA `[PERMANENT_DELEGATE_SEED, token_config]`, permanent delegate of the mint
+    pub const PERMANENT_DELEGATE_SEED: &'static [u8] = b"permanent-delegate";
+
+    pub fn status(&self) -> Result<TokenConfigStatus, ProgramError> {
+        TokenConfigStatus::try_from(self.status)
+    }
+
+    fn resolve_fee_bps(fee_bps: u16, global_config: &GlobalConfig) -> u16 {
+        if fee_bps == FEE_BPS_UNSET {
+            global_config.fees_bps
+        } else {
+            fee_bps
+        }
+    }
+
+    pub fn issuance_fee(&self, global_config: &GlobalConfig, amount: u64) -> u64 {
+        bps_fee(amount, Self::resolve_fee_bps(self.issuance_fee_bps, global_config))
+    }
+
+    pub fn redemption_fee(&self, global_config: &GlobalConfig, amount: u64) -> u64 {
+        bps_fee(amount, Self::resolve_fee_bps(self.redemption_fee_bps, global_config))
+    }
+
+    /// The global `fees_bps` is an issuance rate, an unset transfer fee stays at 0 instead of inheriting it
+    pub fn transfer_fee_bps(&self) -> u16 {
+        if self.transfer_fee_bps == FEE_BPS_UNSET {
+            0
+        } else {
+            self.transfer_fee_bps
+        }
+    }
+
+    pub fn jurisdiction_mode(&self) -> Result<JurisdictionMode, ProgramError> {
+        JurisdictionMode::try_from(self.jurisdiction_mode)
+    }
+
+    /// Checks an approved investor's profile against this asset's rules
+    pub fn check_eligible(&self, investor_kyc: &InvestorKYC) -> ProgramResult {
+        let listed = self.jurisdictions[..2 * self.jurisdiction_count as usize]
+            .chunks_exact(2)
+            .any(|code| code == investor_kyc.jurisdiction);
+        let allowed = match self.jurisdiction_mode()? {
+            JurisdictionMode::Open => true,
+            JurisdictionMode::Allow => listed,
+            JurisdictionMode::Deny => !listed,
+        };
+        if !allowed {
+            return Err(RWAError::JurisdictionNotAllowed.into());
+        }
+        if investor_kyc.accreditation_tier < self.min_accreditation_tier {
+            return Err(RWAError::AccreditationTooLow.into());
+        }
+        Ok(())
+    }
+
+    /// Supply after adding `amount`, failing if it would eat into the room reserved for pending refunds
+    pub fn check_max_supply(&self, supply: u64, amount: u64) -> Result<u64, ProgramError> {
+        let new_supply = supply.checked_add(amount).ok_or(RWAError::MaxSupplyExceeded)?;
+        let reserved = new_supply
+            .checked_add(self.pending_redemption_amount)
+            .ok_or(RWAError::MaxSupplyExceeded)?;
+        if reserved > self.max_supply {
+            return Err(RWAError::MaxSupplyExceeded.into());
+        }
+        Ok(new_supply)
+    }
+
+    /// Checks `account` is the owner this asset's fees are paid to
+    pub fn check_fee_recipient(&self, account: &AccountInfo, program_id: &Pubkey) -> ProgramResult {
+        if self.fee_recipient == Pubkey::default() {
+            GlobalConfig::fee_vault_bump(account, program_id)?;
+        } else if *account.key() != self.fee_recipient {
+            return Err(RWAError::InvalidAddress.into());
+        }
+        Ok(())
+    }
+}
+
+/// Listing state of an asset
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TokenConfigStatus {
+    Pending = 0,
+    Active = 1,
+    Suspended = 2,
+    // Terminal, a retired asset never comes back
+    Retired = 3,
+}
+
+impl TryFrom<u8> for TokenConfigStatus {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TokenConfigStatus::Pending),
+            1 => Ok(TokenConfigStatus::Active),
+            2 => Ok(TokenConfigStatus::Suspended),
+            3 => Ok(TokenConfigStatus::Retired),
+            _ => Err(RWAError::InvalidAccountData.into()),
+        }
+    }
+}
+
+/// What the config authority decided about an asset
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TokenConfigAction {
+    Activate = 0,
+    Suspend = 1,
+    Retire = 2,
+}
+
+impl TryFrom<u8> for TokenConfigAction {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TokenConfigAction::Activate),
+            1 => Ok(TokenConfigAction::Suspend),
+            2 => Ok(TokenConfigAction::Retire),
+            _ => Err(RWAError::InvalidInstructionData.into()),
+        }
+    }
+}
+
+impl TokenConfigAction {
+    /// Status the config moves to, or None if the action doesn't apply to `from`
+    pub fn apply(self, from: TokenConfigStatus) -> Option<TokenConfigStatus> {
+        match (self, from) {
+            (TokenConfigAction::Activate, TokenConfigStatus::Pending | TokenConfigStatus::Suspended) => {
+                Some(TokenConfigStatus::Active)
+            }
+            (TokenConfigAction::Suspend, TokenConfigStatus::Active) => Some(TokenConfigStatus::Suspended),
+            (TokenConfigAction::Retire, TokenConfigStatus::Retired) => None,
+            (TokenConfigAction::Retire, _) => Some(TokenConfigStatus::Retired),
+            _ => None,
+        }
+    }
 }
@@ -1,17 +1,16 @@
 use {
     pinocchio::{
         account_info::AccountInfo,
-        program_error::ProgramError,
         pubkey::find_program_address,
         ProgramResult,
     },
-    pinocchio_associated_token_account::instruction::Create,
+    pinocchio_associated_token_account::instructions::Create,
     crate::errors::RWAError,
 };
 
 
 pub trait AssociatedTokenAccountCheck {
-    fn check(account: &AccountInfo, authority: &AccountInfo, mint: &AccountInfo, token_program: &AccountInfo) -> ProgramResult
+    fn check(account: &AccountInfo, authority: &AccountInfo, mint: &AccountInfo, token_program: &AccountInfo) -> ProgramResult;
 }
 
 pub struct AssociatedTokenAccount;
@@ -25,7 +24,7 @@ impl AssociatedTokenAccountCheck for AssociatedTokenAccount {
     ) -> ProgramResult {
  
         if find_program_address(
-            &[authority.key(), token_program.key(), mint.key()],
+            &[authority.key().as_ref(), token_program.key().as_ref(), mint.key().as_ref()],
             &pinocchio_associated_token_account::ID,
         )
         .0
@@ -56,9 +55,10 @@ impl AssociatedTokenAccountInit for AssociatedTokenAccount {
     }
  
     fn init_if_needed(account: &AccountInfo, mint: &AccountInfo, payer: &AccountInfo, owner: &AccountInfo, system_program: &AccountInfo, token_program: &AccountInfo) -> ProgramResult {
-        match Self::check(account, payer, mint, token_program) {
-            Ok(_) => Ok(()),
-            Err(_) => Self::init(account, mint, payer, owner, system_program, token_program),
+        // The ATA program validates the address itself when creating
+        if account.data_is_empty() {
+            return Self::init(account, mint, payer, owner, system_program, token_program);
         }
+        Self::check(account, owner, mint, token_program)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,492 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::{invoke, invoke_signed},
+        instruction::{AccountMeta, Instruction, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+    crate::utils::{MINT_LEN, TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET, TOKEN_2022_PROGRAM_ID},
+};
+
+// Token-2022 instructions, encoded by hand. pinocchio-token-2022 builds on a different pinocchio,
+// its AccountInfo and Signer types don't match ours.
+// Extension instructions are `[extension instruction][sub instruction][args..]`.
+const FREEZE_ACCOUNT: u8 = 10;
+const THAW_ACCOUNT: u8 = 11;
+const TRANSFER_CHECKED: u8 = 12;
+const MINT_TO_CHECKED: u8 = 14;
+const BURN_CHECKED: u8 = 15;
+const INITIALIZE_MINT_2: u8 = 20;
+const TRANSFER_FEE_EXTENSION: u8 = 23;
+const DEFAULT_ACCOUNT_STATE_EXTENSION: u8 = 25;
+const INITIALIZE_PERMANENT_DELEGATE: u8 = 32;
+const TRANSFER_HOOK_EXTENSION: u8 = 33;
+const METADATA_POINTER_EXTENSION: u8 = 36;
+
+// TLV header: extension type u16 | length u16
+const EXTENSION_HEADER_LEN: usize = 4;
+const TRANSFER_FEE_CONFIG_LEN: usize = 108;
+const TRANSFER_HOOK_LEN: usize = 64;
+const DEFAULT_ACCOUNT_STATE_LEN: usize = 1;
+const METADATA_POINTER_LEN: usize = 64;
+const PERMANENT_DELEGATE_LEN: usize = 32;
+
+/// Extensions CreateRWA can put on a mint. They are initialized before InitializeMint2.
+#[derive(Default)]
+pub struct MintExtensions<'a> {
+    pub transfer_fee: Option<TransferFeeInit<'a>>,
+    pub transfer_hook: Option<TransferHookInit<'a>>,
+    pub metadata_pointer: Option<MetadataPointerInit<'a>>,
+    pub default_frozen: bool,  // DefaultAccountState=Frozen, requires a freeze authority
+    pub permanent_delegate: Option<&'a Pubkey>,
+}
+
+pub struct TransferFeeInit<'a> {
+    pub authority: &'a Pubkey,  // both the config and the withdraw withheld authority
+    pub fee_bps: u16,
+    pub maximum_fee: u64,
+}
+
+pub struct TransferHookInit<'a> {
+    pub authority: &'a Pubkey,
+    pub program_id: &'a Pubkey,
+}
+
+pub struct MetadataPointerInit<'a> {
+    pub authority: &'a Pubkey,
+    pub metadata_address: &'a Pubkey,
+}
+
+impl MintExtensions<'_> {
+    /// Account size of a mint carrying these extensions
+    pub fn mint_len(&self) -> usize {
+        let mut extensions_len = 0;
+        if self.transfer_fee.is_some() {
+            extensions_len += EXTENSION_HEADER_LEN + TRANSFER_FEE_CONFIG_LEN;
+        }
+        if self.transfer_hook.is_some() {
+            extensions_len += EXTENSION_HEADER_LEN + TRANSFER_HOOK_LEN;
+        }
+        if self.metadata_pointer.is_some() {
+            extensions_len += EXTENSION_HEADER_LEN + METADATA_POINTER_LEN;
+        }
+        if self.default_frozen {
+            extensions_len += EXTENSION_HEADER_LEN + DEFAULT_ACCOUNT_STATE_LEN;
+        }
+        if self.permanent_delegate.is_some() {
+            extensions_len += EXTENSION_HEADER_LEN + PERMANENT_DELEGATE_LEN;
+        }
+
+        if extensions_len == 0 {
+            MINT_LEN
+        } else {
+            // Base mint padded to the token account size, then the account type byte
+            TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET + 1 + extensions_len
+        }
+    }
+
+    pub fn initialize(&self, mint: &AccountInfo) -> ProgramResult {
+        if let Some(transfer_fee) = &self.transfer_fee {
+            InitializeTransferFeeConfig {
+                mint,
+                transfer_fee_config_authority: Some(transfer_fee.authority),
+                withdraw_withheld_authority: Some(transfer_fee.authority),
+                transfer_fee_basis_points: transfer_fee.fee_bps,
+                maximum_fee: transfer_fee.maximum_fee,
+            }
+            .invoke()?;
+        }
+        if let Some(transfer_hook) = &self.transfer_hook {
+            InitializeTransferHook {
+                mint,
+                authority: Some(transfer_hook.authority),
+                program_id: Some(transfer_hook.program_id),
+            }
+            .invoke()?;
+        }
+        if let Some(metadata_pointer) = &self.metadata_pointer {
+            InitializeMetadataPointer {
+                mint,
+                authority: Some(metadata_pointer.authority),
+                metadata_address: Some(metadata_pointer.metadata_address),
+            }
+            .invoke()?;
+        }
+        if self.default_frozen {
+            InitializeDefaultAccountState {
+                mint,
+                state: ACCOUNT_STATE_FROZEN,
+            }
+            .invoke()?;
+        }
+        if let Some(delegate) = self.permanent_delegate {
+            InitializePermanentDelegate { mint, delegate }.invoke()?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a `COption<Pubkey>` the way Token-2022 instructions pack it, returns the new offset
+fn write_optional_pubkey(data: &mut [u8], offset: usize, pubkey: Option<&Pubkey>) -> usize {
+    match pubkey {
+        Some(pubkey) => {
+            data[offset] = 1;
+            data[offset + 1..offset + 33].copy_from_slice(pubkey);
+            offset + 33
+        }
+        None => offset + 1,
+    }
+}
+
+/// InitializeMint2, runs after every extension initializer
+pub struct InitializeMint2<'a> {
+    pub mint: &'a AccountInfo,
+    pub decimals: u8,
+    pub mint_authority: &'a Pubkey,
+    pub freeze_authority: Option<&'a Pubkey>,
+}
+
+impl InitializeMint2<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        let mut data = [0u8; 2 + 32 + 33];
+        data[0] = INITIALIZE_MINT_2;
+        data[1] = self.decimals;
+        data[2..34].copy_from_slice(self.mint_authority);
+        let offset = write_optional_pubkey(&mut data, 34, self.freeze_authority);
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[AccountMeta::writable(self.mint.key())],
+            data: &data[..offset],
+        };
+        invoke(&instruction, &[self.mint])
+    }
+}
+
+pub struct InitializeTransferFeeConfig<'a> {
+    pub mint: &'a AccountInfo,
+    pub transfer_fee_config_authority: Option<&'a Pubkey>,
+    pub withdraw_withheld_authority: Option<&'a Pubkey>,
+    pub transfer_fee_basis_points: u16,
+    pub maximum_fee: u64,
+}
+
+impl InitializeTransferFeeConfig<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        let mut data = [0u8; 2 + 33 + 33 + 2 + 8];
+        data[0] = TRANSFER_FEE_EXTENSION;
+        data[1] = 0;
+        let mut offset = write_optional_pubkey(&mut data, 2, self.transfer_fee_config_authority);
+        offset = write_optional_pubkey(&mut data, offset, self.withdraw_withheld_authority);
+        data[offset..offset + 2].copy_from_slice(&self.transfer_fee_basis_points.to_le_bytes());
+        data[offset + 2..offset + 10].copy_from_slice(&self.maximum_fee.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[AccountMeta::writable(self.mint.key())],
+            data: &data[..offset + 10],
+        };
+        invoke(&instruction, &[self.mint])
+    }
+}
+
+/// Writes an `OptionalNonZeroPubkey`, all zeroes standing for None
+fn write_nonzero_pubkey(data: &mut [u8], offset: usize, pubkey: Option<&Pubkey>) -> usize {
+    if let Some(pubkey) = pubkey {
+        data[offset..offset + 32].copy_from_slice(pubkey);
+    }
+    offset + 32
+}
+
+pub struct InitializeTransferHook<'a> {
+    pub mint: &'a AccountInfo,
+    pub authority: Option<&'a Pubkey>,
+    pub program_id: Option<&'a Pubkey>,
+}
+
+impl InitializeTransferHook<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        let mut data = [0u8; 2 + 32 + 32];
+        data[0] = TRANSFER_HOOK_EXTENSION;
+        data[1] = 0;
+        let offset = write_nonzero_pubkey(&mut data, 2, self.authority);
+        write_nonzero_pubkey(&mut data, offset, self.program_id);
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[AccountMeta::writable(self.mint.key())],
+            data: &data,
+        };
+        invoke(&instruction, &[self.mint])
+    }
+}
+
+/// `AccountState` as Token-2022 packs it
+const ACCOUNT_STATE_FROZEN: u8 = 2;
+
+pub struct InitializeDefaultAccountState<'a> {
+    pub mint: &'a AccountInfo,
+    pub state: u8,
+}
+
+impl InitializeDefaultAccountState<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        let data = [DEFAULT_ACCOUNT_STATE_EXTENSION, 0, self.state];
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[AccountMeta::writable(self.mint.key())],
+            data: &data,
+        };
+        invoke(&instruction, &[self.mint])
+    }
+}
+
+pub struct InitializeMetadataPointer<'a> {
+    pub mint: &'a AccountInfo,
+    pub authority: Option<&'a Pubkey>,
+    pub metadata_address: Option<&'a Pubkey>,
+}
+
+impl InitializeMetadataPointer<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        let mut data = [0u8; 2 + 32 + 32];
+        data[0] = METADATA_POINTER_EXTENSION;
+        data[1] = 0;
+        let offset = write_nonzero_pubkey(&mut data, 2, self.authority);
+        write_nonzero_pubkey(&mut data, offset, self.metadata_address);
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[AccountMeta::writable(self.mint.key())],
+            data: &data,
+        };
+        invoke(&instruction, &[self.mint])
+    }
+}
+
+/// Not an extension sub-instruction, the delegate can't be changed once set
+pub struct InitializePermanentDelegate<'a> {
+    pub mint: &'a AccountInfo,
+    pub delegate: &'a Pubkey,
+}
+
+impl InitializePermanentDelegate<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        let mut data = [0u8; 1 + 32];
+        data[0] = INITIALIZE_PERMANENT_DELEGATE;
+        data[1..].copy_from_slice(self.delegate);
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[AccountMeta::writable(self.mint.key())],
+            data: &data,
+        };
+        invoke(&instruction, &[self.mint])
+    }
+}
+
+/// Accounts Token-2022 needs to run this program's transfer hook, see `ExtraAccountMetas`
+pub struct TransferHookAccounts<'a> {
+    pub extra_account_metas: &'a AccountInfo,
+    pub global_config: &'a AccountInfo,
+    pub token_config: &'a AccountInfo,
+    pub sender_kyc: &'a AccountInfo,
+    pub receiver_kyc: &'a AccountInfo,
+    pub hook_program: &'a AccountInfo,
+}
+
+/// TransferChecked on one of our mints, forwarding the hook accounts
+pub struct TransferCheckedWithHook<'a> {
+    pub from: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub to: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub amount: u64,
+    pub decimals: u8,
+    pub hook: TransferHookAccounts<'a>,
+}
+
+impl TransferCheckedWithHook<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut data = [0u8; 10];
+        data[0] = TRANSFER_CHECKED;
+        data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        data[9] = self.decimals;
+
+        let hook = &self.hook;
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[
+                AccountMeta::writable(self.from.key()),
+                AccountMeta::readonly(self.mint.key()),
+                AccountMeta::writable(self.to.key()),
+                AccountMeta::readonly_signer(self.authority.key()),
+                AccountMeta::readonly(hook.extra_account_metas.key()),
+                AccountMeta::readonly(hook.global_config.key()),
+                AccountMeta::readonly(hook.token_config.key()),
+                AccountMeta::readonly(hook.sender_kyc.key()),
+                AccountMeta::readonly(hook.receiver_kyc.key()),
+                AccountMeta::readonly(hook.hook_program.key()),
+            ],
+            data: &data,
+        };
+        invoke_signed(
+            &instruction,
+            &[
+                self.from,
+                self.mint,
+                self.to,
+                self.authority,
+                hook.extra_account_metas,
+                hook.global_config,
+                hook.token_config,
+                hook.sender_kyc,
+                hook.receiver_kyc,
+                hook.hook_program,
+            ],
+            signers,
+        )
+    }
+}
+
+/// MintToChecked, the mint authority is one of our PDAs
+pub struct MintToChecked<'a> {
+    pub mint: &'a AccountInfo,
+    pub account: &'a AccountInfo,
+    pub mint_authority: &'a AccountInfo,
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+impl MintToChecked<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut data = [0u8; 10];
+        data[0] = MINT_TO_CHECKED;
+        data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        data[9] = self.decimals;
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[
+                AccountMeta::writable(self.mint.key()),
+                AccountMeta::writable(self.account.key()),
+                AccountMeta::readonly_signer(self.mint_authority.key()),
+            ],
+            data: &data,
+        };
+        invoke_signed(&instruction, &[self.mint, self.account, self.mint_authority], signers)
+    }
+}
+
+/// BurnChecked, the token account owner signs
+pub struct BurnChecked<'a> {
+    pub account: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+impl BurnChecked<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        let mut data = [0u8; 10];
+        data[0] = BURN_CHECKED;
+        data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        data[9] = self.decimals;
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[
+                AccountMeta::writable(self.account.key()),
+                AccountMeta::writable(self.mint.key()),
+                AccountMeta::readonly_signer(self.authority.key()),
+            ],
+            data: &data,
+        };
+        invoke(&instruction, &[self.account, self.mint, self.authority])
+    }
+}
+
+/// FreezeAccount, signed by the mint's freeze authority
+pub struct FreezeAccount<'a> {
+    pub account: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub freeze_authority: &'a AccountInfo,
+}
+
+impl FreezeAccount<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[
+                AccountMeta::writable(self.account.key()),
+                AccountMeta::readonly(self.mint.key()),
+                AccountMeta::readonly_signer(self.freeze_authority.key()),
+            ],
+            data: &[FREEZE_ACCOUNT],
+        };
+        invoke_signed(&instruction, &[self.account, self.mint, self.freeze_authority], signers)
+    }
+}
+
+/// ThawAccount, same accounts as FreezeAccount
+pub struct ThawAccount<'a> {
+    pub account: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub freeze_authority: &'a AccountInfo,
+}
+
+impl ThawAccount<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[
+                AccountMeta::writable(self.account.key()),
+                AccountMeta::readonly(self.mint.key()),
+                AccountMeta::readonly_signer(self.freeze_authority.key()),
+            ],
+            data: &[THAW_ACCOUNT],
+        };
+        invoke_signed(&instruction, &[self.account, self.mint, self.freeze_authority], signers)
+    }
+}
+
+/// Permissionless, moves the fees withheld on `source` into the mint
+pub struct HarvestWithheldTokensToMint<'a> {
+    pub mint: &'a AccountInfo,
+    pub source: &'a AccountInfo,
+}
+
+impl HarvestWithheldTokensToMint<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[AccountMeta::writable(self.mint.key()), AccountMeta::writable(self.source.key())],
+            data: &[TRANSFER_FEE_EXTENSION, 4],
+        };
+        invoke(&instruction, &[self.mint, self.source])
+    }
+}
+
+pub struct WithdrawWithheldTokensFromMint<'a> {
+    pub mint: &'a AccountInfo,
+    pub destination: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+}
+
+impl WithdrawWithheldTokensFromMint<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[
+                AccountMeta::writable(self.mint.key()),
+                AccountMeta::writable(self.destination.key()),
+                AccountMeta::readonly_signer(self.authority.key()),
+            ],
+            data: &[TRANSFER_FEE_EXTENSION, 2],
+        };
+        invoke_signed(&instruction, &[self.mint, self.destination, self.authority], signers)
+    }
+}
@@ -0,0 +1,24 @@
+/// SHA-256 over the concatenation of `vals`, via the sol_sha256 syscall on-chain
+#[inline(always)]
+pub fn sha256(vals: &[&[u8]]) -> [u8; 32] {
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash = [0u8; 32];
+        // A &[u8] is laid out as (ptr, len), which is the slice format the syscall expects
+        unsafe {
+            pinocchio::syscalls::sol_sha256(vals as *const _ as *const u8, vals.len() as u64, hash.as_mut_ptr());
+        }
+        hash
+    }
+
+    // Off-chain (host tests, clients) there is no syscall, hash in pure Rust instead
+    #[cfg(not(target_os = "solana"))]
+    {
+        use sha2::{Digest, Sha256};
+        let mut hasher = Sha256::new();
+        for val in vals {
+            hasher.update(val);
+        }
+        hasher.finalize().into()
+    }
+}
@@ -2,22 +2,15 @@ use {
     pinocchio::{
         account_info::AccountInfo,
         program_error::ProgramError,
-        pubkey::Pubkey,
-        rent::Rent,
-        sysvar::Sysvar,
-        program::invoke_signed,
-        system_instruction::create_account,
-        Signer,
+        instruction::{Seed, Signer},
+        sysvars::{rent::Rent, Sysvar},
         ProgramResult
     },
+    pinocchio_system::instructions::CreateAccount,
     core::convert::TryFrom,
-    core::mem::size_of,
     crate::errors::RWAError,
-    crate::states::GlobalConfig,
     crate::ID,
-    crate::utils::{
-        AccountCheck, load_acc_mut_unchecked
-    },
+    crate::utils::AccountCheck,
 };
 
 /// Signer account
@@ -28,9 +21,9 @@ pub struct SignerAccount<'a> {
 impl<'a> TryFrom<&'a AccountInfo> for SignerAccount<'a> {
     type Error = ProgramError;
 
-    fn try_from(account: &'a AccountInfo) -> ProgramResult {
+    fn try_from(account: &'a AccountInfo) -> Result<Self, Self::Error> {
         SignerAccount::check(account)?;
-        Ok(account)
+        Ok(Self { account })
     }
 }
 
@@ -51,15 +44,15 @@ pub struct SystemAccount<'a> {
 impl<'a> TryFrom<&'a AccountInfo> for SystemAccount<'a> {
     type Error = ProgramError;
 
-    fn try_from(account: &'a AccountInfo) -> ProgramResult {
+    fn try_from(account: &'a AccountInfo) -> Result<Self, Self::Error> {
         SystemAccount::check(account)?;
-        Ok(account)
+        Ok(Self { account })
     }
 }
 
 impl<'a> AccountCheck for SystemAccount<'a> {
     fn check(account: &AccountInfo) -> ProgramResult {
-        if !account.is_owned_by(&pinocchio::system_program::ID) {
+        if !account.is_owned_by(&pinocchio_system::ID) {
             return Err(RWAError::InvalidOwner.into());
         }
         Ok(())
@@ -74,16 +67,14 @@ pub struct ProgramAccount<'a> {
 impl<'a> TryFrom<&'a AccountInfo> for ProgramAccount<'a> {
     type Error = ProgramError;
 
-    fn try_from(account: &'a AccountInfo) -> ProgramResult {
+    fn try_from(account: &'a AccountInfo) -> Result<Self, Self::Error> {
         ProgramAccount::check(account)?;
         Ok(Self { account })
     }
 }
 
 impl<'a> AccountCheck for ProgramAccount<'a> {
-    fn check(account: &'a AccountInfo) -> ProgramResult {
-         
-
+    fn check(account: &AccountInfo) -> ProgramResult {
         if !account.is_owned_by(&ID) {
             return Err(RWAError::InvalidOwner.into());
         }
@@ -97,14 +88,14 @@ pub trait ProgramAccountInit {
     fn init<'a>(
         payer: &'a AccountInfo,
         account: &'a AccountInfo,
-        seeds: &[&[u8]],
+        seeds: &[Seed],
         space: usize,
     ) -> Result<(), ProgramError>;
 
     fn init_if_needed<'a>(
         payer: &'a AccountInfo,
         account: &'a AccountInfo,
-        seeds: &[&[u8]],
+        seeds: &[Seed],
         space: usize,
     ) -> Result<(), ProgramError>;
 }
@@ -113,28 +104,26 @@ impl ProgramAccountInit for ProgramAccount<'_> {
     fn init<'a>(
         payer: &'a AccountInfo,
         account: &'a AccountInfo,
-        seeds: &[&[u8]],
+        seeds: &[Seed],
         space: usize,
     ) -> ProgramResult {
         let lamports = Rent::get()?.minimum_balance(space);
+        let signer_seeds = [Signer::from(seeds)];
 
-        let ix = create_account(
-            payer.key(),
-            account.key(),
+        CreateAccount {
+            from: payer,
+            to: account,
             lamports,
-            space as u64,
-            &ID,
-        );
-        let signer_seeds = Signer::from(signer_seeds);
-
-        invoke_signed(&ix, &[&payer, &account], &[signer_seeds])?;
-        Ok(())
+            space: space as u64,
+            owner: &ID,
+        }
+        .invoke_signed(&signer_seeds)
     }
 
     fn init_if_needed<'a>(
         payer: &'a AccountInfo,
         account: &'a AccountInfo,
-        seeds: &[&[u8]],
+        seeds: &[Seed],
         space: usize,
     ) -> ProgramResult {
         match ProgramAccount::check(account) {
@@ -146,16 +135,28 @@ impl ProgramAccountInit for ProgramAccount<'_> {
 
 /// Trait for closing a program account safely
 pub trait AccountClose {
-    fn close(account: &'a AccountInfo, destination: &'a AccountInfo) -> ProgramResult;
+    fn close(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult;
 }
 
-impl<'a> AccountClose<'a> for ProgramAccount<'_> {
-    fn close<'a>(account: &'a AccountInfo, destination: &'a AccountInfo) -> ProgramResult {
-        let lamports = **account.lamports.borrow();
-        **destination.lamports.borrow_mut() += lamports;
-        **account.lamports.borrow_mut() = 0;
+impl AccountClose for ProgramAccount<'_> {
+    /// Moves every lamport to `destination`, then hands the account back to the system
+    /// program with no data, so nothing can load it as one of ours again
+    fn close(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+        // Refunding into the account being closed would burn the lamports
+        if account.key() == destination.key() {
+            return Err(RWAError::InvalidAddress.into());
+        }
+        {
+            account.try_borrow_mut_data()?.fill(0);
+        }
+
+        let lamports = account.lamports();
+        *destination.try_borrow_mut_lamports()? = destination
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        *account.try_borrow_mut_lamports()? = 0;
 
-        account.try_borrow_mut_data()?.fill(0);
-        Ok(())
+        account.close()
     }
 }
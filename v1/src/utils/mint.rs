@@ -1,78 +1,86 @@
 use {
     pinocchio::{
         account_info::AccountInfo,
-        instruction::Signer,
         program_error::ProgramError,
-        pubkey::{
-            Pubkey,
-            find_program_address},
-        rent::Rent,
-        seeds,
+        pubkey::Pubkey,
+        sysvars::{rent::Rent, Sysvar},
         ProgramResult
     },
-    pinocchio_token_2022::{
-        instructions::{CreateAccount, InitializeMint2},
-        states::Mint,
-    },
+    pinocchio_system::instructions::CreateAccount,
     crate::{
         errors::RWAError,
-        utils::{AccountCheck, load_acc_mut_unchecked},
-        states::TokenMetadata
+        utils::{
+            AccountCheck, InitializeMint2, MintExtensions,
+            TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET, TOKEN_2022_MINT_DISCRIMINATOR, TOKEN_2022_PROGRAM_ID,
+        },
     },
 };
 
-/// Trait for initializing mints and metadata
+/// Trait for initializing mints
 pub trait MintInit {
     fn init(
         account: &AccountInfo,
         payer: &AccountInfo,
         decimals: u8,
-        mint_authority: &[u8; 32],
-        freeze_authority: Option<&[u8; 32]>,
+        mint_authority: &Pubkey,
+        freeze_authority: Option<&Pubkey>,
     ) -> ProgramResult;
 
-    fn init_if_needed(
+    fn init_with_extensions(
         account: &AccountInfo,
         payer: &AccountInfo,
         decimals: u8,
-        mint_authority: &[u8; 32],
-        freeze_authority: Option<&[u8; 32]>,
+        mint_authority: &Pubkey,
+        freeze_authority: Option<&Pubkey>,
+        extensions: &MintExtensions,
     ) -> ProgramResult;
 
-    fn init_metadata_account(
-        metadata: &AccountInfo,
-        mint_ai: &AccountInfo,
-        program_id: &Pubkey,
-        name: &str,
-        symbol: &str,
-        uri: &str,
-    ) -> ProgramResult;
-
-    fn init_if_needed_and_init_metadata_account(
+    fn init_if_needed(
         account: &AccountInfo,
         payer: &AccountInfo,
         decimals: u8,
-        mint_authority: &[u8; 32],
-        freeze_authority: Option<&[u8; 32]>,
-        metadata_account: &AccountInfo,
-        program_id: &Pubkey,
-        name: &str,
-        symbol: &str,
-        uri: &str,
+        mint_authority: &Pubkey,
+        freeze_authority: Option<&Pubkey>,
     ) -> ProgramResult;
 }
 
 /// Represents a Mint (Token-2022)
 pub struct Mint2022Account;
 
-impl<TokenMetadata> AccountCheck for Mint2022Account {
+/// Size of a mint without extensions
+pub const MINT_LEN: usize = 82;
+
+// Base mint layout: mint_authority COption<Pubkey> (36) | supply u64 | decimals u8 | ...
+const MINT_SUPPLY_OFFSET: usize = 36;
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+impl Mint2022Account {
+    /// Current supply read straight from the mint data
+    pub fn supply(account: &AccountInfo) -> Result<u64, ProgramError> {
+        let data = account.try_borrow_data()?;
+        let bytes = data
+            .get(MINT_SUPPLY_OFFSET..MINT_SUPPLY_OFFSET + 8)
+            .ok_or(RWAError::InvalidAccountData)?;
+        Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    pub fn decimals(account: &AccountInfo) -> Result<u8, ProgramError> {
+        let data = account.try_borrow_data()?;
+        data.get(MINT_DECIMALS_OFFSET).copied().ok_or(RWAError::InvalidAccountData.into())
+    }
+}
+
+impl AccountCheck for Mint2022Account {
     fn check(account: &AccountInfo) -> ProgramResult {
-        if !account.is_owned_by(&pinocchio_token_2022::ID) {
+        if !account.is_owned_by(&TOKEN_2022_PROGRAM_ID) {
             return Err(RWAError::InvalidOwner.into());
         }
 
+        // A bare mint is exactly MINT_LEN, one with extensions carries the mint account type
         let data = account.try_borrow_data()?;
-        if data.len() != Mint::LEN {
+        if data.len() != MINT_LEN
+            && data.get(TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET) != Some(&TOKEN_2022_MINT_DISCRIMINATOR)
+        {
             return Err(RWAError::InvalidAccountData.into());
         }
 
@@ -85,30 +93,43 @@ impl MintInit for Mint2022Account {
         account: &AccountInfo,
         payer: &AccountInfo,
         decimals: u8,
-        mint_authority: &[u8; 32],
-        freeze_authority: Option<&[u8; 32]>,
-        token_program: &[u8; 32],
+        mint_authority: &Pubkey,
+        freeze_authority: Option<&Pubkey>,
+    ) -> ProgramResult {
+        Self::init_with_extensions(account, payer, decimals, mint_authority, freeze_authority, &MintExtensions::default())
+    }
+
+    fn init_with_extensions(
+        account: &AccountInfo,
+        payer: &AccountInfo,
+        decimals: u8,
+        mint_authority: &Pubkey,
+        freeze_authority: Option<&Pubkey>,
+        extensions: &MintExtensions,
     ) -> ProgramResult {
+        let space = extensions.mint_len();
         // Get required lamports for rent
-        let lamports = Rent::get()?.minimum_balance(Mint::LEN);
+        let lamports = Rent::get()?.minimum_balance(space);
 
-        // Create account
+        // Create account, the mint is a fresh keypair so it signs the outer transaction
         CreateAccount {
             from: payer,
             to: account,
             lamports,
-            space: Mint::LEN as u64,
-            owner: &crate::ID,
+            space: space as u64,
+            owner: &TOKEN_2022_PROGRAM_ID,
         }
         .invoke()?;
 
+        // Token-2022 only accepts extension initializers before InitializeMint2
+        extensions.initialize(account)?;
+
         // Initialize mint
         InitializeMint2 {
             mint: account,
             decimals,
             mint_authority,
             freeze_authority,
-            token_program,
         }
         .invoke()?;
 
@@ -119,73 +140,12 @@ impl MintInit for Mint2022Account {
         account: &AccountInfo,
         payer: &AccountInfo,
         decimals: u8,
-        mint_authority: &[u8; 32],
-        freeze_authority: Option<&[u8; 32]>,
+        mint_authority: &Pubkey,
+        freeze_authority: Option<&Pubkey>,
     ) -> ProgramResult {
         match Self::check(account) {
             Ok(_) => Ok(()),
             Err(_) => Self::init(account, payer, decimals, mint_authority, freeze_authority),
         }
     }
-
-    fn init_metadata_account(
-        metadata: &AccountInfo,
-        mint: &AccountInfo,
-        authority: &AccountInfo,
-        program_id: &Pubkey,
-        name: &str,
-        symbol: &str,
-        uri: &str,
-    ) -> ProgramResult {
-        // Derive PDA
-        let (expected_pda, bump) =
-            find_program_address(&[b"metadata", mint.key().as_ref()], program_id);
-
-        if metadata.key() != &expected_pda {
-            return Err(ProgramError::InvalidSeeds);
-        }
-        let seeds = seeds!(b"metadata", mint.key().as_ref(), program_id);
-        let signer_seeds = Signer::from(seeds);
-
-        let _ = ProgramAccount::init_if_needed(&signer, &metadata, signer_seeds, core::mem::size_of::<TokenMetadata>());
-        // Borrow PDA buffer
-        let mut data = metadata.try_borrow_mut_data()?;
-        let metadata: &mut crate::states::TokenMetadata =
-            unsafe { load_acc_mut_unchecked::<TokenMetadata>(&mut data)? };
-
-            if name.len() > 32 {
-                return Err(RWAError::InvalidInstructionData.into())?;
-            }
-        // Fill metadata
-        metadata.mint = *mint.key().as_ref();
-        metadata.authority = *authority.key().as_ref();
-        metadata.name = name.bytes();
-        metadata.symbol = symbol.bytes();
-        metadata.uri = uri.bytes();
-        metadata.bump = bump;
-
-
-        Ok(())
-    }
-
-    fn init_if_needed_and_init_metadata_account(
-        account: &AccountInfo,
-        payer: &AccountInfo,
-        decimals: u8,
-        mint_authority: &[u8; 32],
-        freeze_authority: Option<&[u8; 32]>,
-        metadata_account: &AccountInfo,
-        program_id: &Pubkey,
-        name: &str,
-        symbol: &str,
-        uri: &str,
-    ) -> ProgramResult {
-        match Self::check(account) {
-            Ok(_) => Ok(()),
-            Err(_) => {
-                Self::init(account, payer, decimals, mint_authority, freeze_authority)?;
-                Self::init_metadata_account(metadata_account, account, program_id, name, symbol, uri)
-            }
-        }
-    }
 }
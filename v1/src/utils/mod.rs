@@ -3,16 +3,26 @@ use {
         account_info::AccountInfo,
         program_error::ProgramError
     },
-    crate::erors::RWAError,
+    crate::errors::RWAError,
 };
 
 pub mod ata;
+pub mod extensions;
+pub mod hash;
 pub mod helper;
 pub mod mint;
+pub mod token_account;
+pub mod token_metadata;
+pub mod transfer_hook;
 
 pub use ata::*;
+pub use extensions::*;
+pub use hash::*;
 pub use helper::*;
 pub use mint::*;
+pub use token_account::*;
+pub use token_metadata::*;
+pub use transfer_hook::*;
 
 // TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb
 pub const TOKEN_2022_PROGRAM_ID: [u8; 32] = [
@@ -35,22 +45,29 @@ pub trait DataLen {
 }
 
 /// Zero-copy helpers
+///
+/// # Safety
+/// `T` must be `#[repr(C)]` with alignment 1 and valid for any bit pattern in `bytes`.
 #[inline(always)]
 pub unsafe fn load_acc_unchecked<T: DataLen>(bytes: &[u8]) -> Result<&T, ProgramError> {
     if bytes.len() != T::LEN {
-        return Err(RWAError::InvalidAccountData);
+        return Err(RWAError::InvalidAccountData.into());
     }
     Ok(&*(bytes.as_ptr() as *const T))
 }
 
+/// # Safety
+/// `T` must be `#[repr(C)]` with alignment 1 and valid for any bit pattern in `bytes`.
 #[inline(always)]
 pub unsafe fn load_acc_mut_unchecked<T: DataLen>(bytes: &mut [u8]) -> Result<&mut T, ProgramError> {
     if bytes.len() != T::LEN {
-        return Err(RWAError::InvalidAccountData);
+        return Err(RWAError::InvalidAccountData.into());
     }
     Ok(&mut *(bytes.as_mut_ptr() as *mut T))
 }
 
+/// # Safety
+/// `T` must be `#[repr(C)]` with alignment 1 and valid for any bit pattern in `bytes`.
 #[inline(always)]
 pub unsafe fn load_ix_data<T: DataLen>(bytes: &[u8]) -> Result<&T, ProgramError> {
     if bytes.len() != T::LEN {
@@ -59,11 +76,15 @@ pub unsafe fn load_ix_data<T: DataLen>(bytes: &[u8]) -> Result<&T, ProgramError>
     Ok(&*(bytes.as_ptr() as *const T))
 }
 
+/// # Safety
+/// `T` must be `#[repr(C)]` without padding, so every byte of it is initialized.
 #[inline(always)]
 pub unsafe fn to_bytes<T: DataLen>(data: &T) -> &[u8] {
     core::slice::from_raw_parts(data as *const T as *const u8, T::LEN)
 }
 
+/// # Safety
+/// `T` must be `#[repr(C)]` without padding, so every byte of it is initialized.
 #[inline(always)]
 pub unsafe fn to_mut_bytes<T: DataLen>(data: &mut T) -> &mut [u8] {
     core::slice::from_raw_parts_mut(data as *mut T as *mut u8, T::LEN)
@@ -0,0 +1,94 @@
+use {
+    pinocchio::{account_info::AccountInfo, instruction::Signer, program_error::ProgramError, pubkey::Pubkey, ProgramResult},
+    crate::{errors::RWAError, utils::{FreezeAccount, ThawAccount, TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET}},
+};
+
+// Base token account layout: mint (32) | owner (32) | amount u64 | delegate COption<Pubkey> (36) | state u8 | ...
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+const TOKEN_ACCOUNT_STATE_OFFSET: usize = 108;
+const ACCOUNT_STATE_FROZEN: u8 = 2;
+
+// TLV entries follow the account type byte
+const EXTENSIONS_OFFSET: usize = TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET + 1;
+const TRANSFER_HOOK_ACCOUNT_EXTENSION: u16 = 15;
+
+/// Read-only views into a Token-2022 token account
+pub struct TokenAccount2022;
+
+impl TokenAccount2022 {
+    pub fn owner(account: &AccountInfo) -> Result<Pubkey, ProgramError> {
+        let data = account.try_borrow_data()?;
+        let bytes = data
+            .get(TOKEN_ACCOUNT_OWNER_OFFSET..TOKEN_ACCOUNT_OWNER_OFFSET + 32)
+            .ok_or(RWAError::InvalidAccountData)?;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    pub fn amount(account: &AccountInfo) -> Result<u64, ProgramError> {
+        let data = account.try_borrow_data()?;
+        let bytes = data
+            .get(TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8)
+            .ok_or(RWAError::InvalidAccountData)?;
+        Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    pub fn is_frozen(account: &AccountInfo) -> Result<bool, ProgramError> {
+        let data = account.try_borrow_data()?;
+        let state = data.get(TOKEN_ACCOUNT_STATE_OFFSET).ok_or(RWAError::InvalidAccountData)?;
+        Ok(*state == ACCOUNT_STATE_FROZEN)
+    }
+
+    /// Thaws `account` if it is frozen, e.g. fresh off a DefaultAccountState=Frozen mint
+    pub fn thaw_if_frozen(
+        account: &AccountInfo,
+        mint: &AccountInfo,
+        freeze_authority: &AccountInfo,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        if !Self::is_frozen(account)? {
+            return Ok(());
+        }
+        ThawAccount {
+            account,
+            mint,
+            freeze_authority,
+        }
+        .invoke_signed(signers)
+    }
+
+    /// Freezes `account` unless it already is
+    pub fn freeze_if_thawed(
+        account: &AccountInfo,
+        mint: &AccountInfo,
+        freeze_authority: &AccountInfo,
+        signers: &[Signer],
+    ) -> ProgramResult {
+        if Self::is_frozen(account)? {
+            return Ok(());
+        }
+        FreezeAccount {
+            account,
+            mint,
+            freeze_authority,
+        }
+        .invoke_signed(signers)
+    }
+
+    /// Whether Token-2022 flagged the account as mid-transfer, set only while it calls the hook
+    pub fn is_transferring(account: &AccountInfo) -> Result<bool, ProgramError> {
+        let data = account.try_borrow_data()?;
+        let mut offset = EXTENSIONS_OFFSET;
+
+        while let Some(header) = data.get(offset..offset + 4) {
+            let extension_type = u16::from_le_bytes([header[0], header[1]]);
+            let len = u16::from_le_bytes([header[2], header[3]]) as usize;
+            if extension_type == TRANSFER_HOOK_ACCOUNT_EXTENSION {
+                return Ok(data.get(offset + 4) == Some(&1));
+            }
+            offset += 4 + len;
+        }
+
+        Ok(false)
+    }
+}
@@ -0,0 +1,179 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed,
+        instruction::{AccountMeta, Instruction, Signer},
+        pubkey::Pubkey,
+        sysvars::{rent::Rent, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_system::instructions::Transfer,
+    crate::{errors::RWAError, utils::TOKEN_2022_PROGRAM_ID},
+};
+
+// Token metadata interface instructions, Token-2022 implements them for mints that
+// point their MetadataPointer at themselves. `[discriminator: 8][borsh args..]`.
+const INITIALIZE_DISCRIMINATOR: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+const UPDATE_FIELD_DISCRIMINATOR: [u8; 8] = [221, 233, 49, 45, 181, 202, 220, 200];
+const UPDATE_AUTHORITY_DISCRIMINATOR: [u8; 8] = [215, 228, 166, 228, 84, 100, 86, 123];
+
+/// Longest key / value this program writes into the TokenMetadata extension
+pub const MAX_METADATA_KEY_LEN: usize = 32;
+pub const MAX_METADATA_VALUE_LEN: usize = 200;
+
+/// Additional metadata keys CreateRWA fills from the TokenConfig
+pub const ASSET_TYPE_KEY: &[u8] = b"asset_type";
+pub const AUDIT_CID_KEY: &[u8] = b"audit_cid";
+
+/// A NUL padded fixed-length field up to its first NUL
+pub fn trim_nul(bytes: &[u8]) -> &[u8] {
+    let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    &bytes[..len]
+}
+
+/// Writes a borsh `String` (u32 LE length + bytes), returns the new offset
+fn write_str(data: &mut [u8], offset: usize, value: &[u8]) -> usize {
+    data[offset..offset + 4].copy_from_slice(&(value.len() as u32).to_le_bytes());
+    data[offset + 4..offset + 4 + value.len()].copy_from_slice(value);
+    offset + 4 + value.len()
+}
+
+/// Token-2022 reallocs the mint for metadata but leaves the rent to the caller,
+/// so top the account up before the instruction ends
+pub fn fund_rent_exemption(account: &AccountInfo, payer: &AccountInfo) -> ProgramResult {
+    let required = Rent::get()?.minimum_balance(account.data_len());
+    let lamports = account.lamports();
+    if lamports < required {
+        Transfer {
+            from: payer,
+            to: account,
+            lamports: required - lamports,
+        }
+        .invoke()?;
+    }
+    Ok(())
+}
+
+/// Initializes the TokenMetadata extension stored on the mint itself
+pub struct InitializeTokenMetadata<'a> {
+    pub mint: &'a AccountInfo,
+    pub update_authority: &'a AccountInfo,
+    pub mint_authority: &'a AccountInfo,
+    pub name: &'a [u8],
+    pub symbol: &'a [u8],
+    pub uri: &'a [u8],
+}
+
+impl InitializeTokenMetadata<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut data = [0u8; 8 + 3 * (4 + MAX_METADATA_VALUE_LEN)];
+        if self.name.len() > MAX_METADATA_VALUE_LEN
+            || self.symbol.len() > MAX_METADATA_VALUE_LEN
+            || self.uri.len() > MAX_METADATA_VALUE_LEN
+        {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+        data[..8].copy_from_slice(&INITIALIZE_DISCRIMINATOR);
+        let mut offset = write_str(&mut data, 8, self.name);
+        offset = write_str(&mut data, offset, self.symbol);
+        offset = write_str(&mut data, offset, self.uri);
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[
+                AccountMeta::writable(self.mint.key()),
+                AccountMeta::readonly(self.update_authority.key()),
+                AccountMeta::readonly(self.mint.key()),
+                AccountMeta::readonly_signer(self.mint_authority.key()),
+            ],
+            data: &data[..offset],
+        };
+        invoke_signed(
+            &instruction,
+            &[self.mint, self.update_authority, self.mint, self.mint_authority],
+            signers,
+        )
+    }
+}
+
+/// Field of the TokenMetadata extension, `Key` adds or replaces an additional field
+pub enum MetadataField<'a> {
+    Name,
+    Symbol,
+    Uri,
+    Key(&'a [u8]),
+}
+
+pub struct UpdateTokenMetadataField<'a> {
+    pub mint: &'a AccountInfo,
+    pub update_authority: &'a AccountInfo,
+    pub field: MetadataField<'a>,
+    pub value: &'a [u8],
+}
+
+impl UpdateTokenMetadataField<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut data = [0u8; 8 + 1 + 4 + MAX_METADATA_KEY_LEN + 4 + MAX_METADATA_VALUE_LEN];
+        if self.value.len() > MAX_METADATA_VALUE_LEN {
+            return Err(RWAError::InvalidInstructionData.into());
+        }
+        data[..8].copy_from_slice(&UPDATE_FIELD_DISCRIMINATOR);
+        let mut offset = match self.field {
+            MetadataField::Name => 9,
+            MetadataField::Symbol => {
+                data[8] = 1;
+                9
+            }
+            MetadataField::Uri => {
+                data[8] = 2;
+                9
+            }
+            MetadataField::Key(key) => {
+                if key.is_empty() || key.len() > MAX_METADATA_KEY_LEN {
+                    return Err(RWAError::InvalidInstructionData.into());
+                }
+                data[8] = 3;
+                write_str(&mut data, 9, key)
+            }
+        };
+        offset = write_str(&mut data, offset, self.value);
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[
+                AccountMeta::writable(self.mint.key()),
+                AccountMeta::readonly_signer(self.update_authority.key()),
+            ],
+            data: &data[..offset],
+        };
+        invoke_signed(&instruction, &[self.mint, self.update_authority], signers)
+    }
+}
+
+/// Hands the metadata to `new_authority`, `None` freezes it for good
+pub struct UpdateTokenMetadataAuthority<'a> {
+    pub mint: &'a AccountInfo,
+    pub update_authority: &'a AccountInfo,
+    pub new_authority: Option<&'a Pubkey>,
+}
+
+impl UpdateTokenMetadataAuthority<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // OptionalNonZeroPubkey, all zeroes standing for None
+        let mut data = [0u8; 8 + 32];
+        data[..8].copy_from_slice(&UPDATE_AUTHORITY_DISCRIMINATOR);
+        if let Some(new_authority) = self.new_authority {
+            data[8..].copy_from_slice(new_authority);
+        }
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &[
+                AccountMeta::writable(self.mint.key()),
+                AccountMeta::readonly_signer(self.update_authority.key()),
+            ],
+            data: &data,
+        };
+        invoke_signed(&instruction, &[self.mint, self.update_authority], signers)
+    }
+}
@@ -0,0 +1,113 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{find_program_address, Pubkey},
+        seeds,
+        ProgramResult,
+    },
+    crate::{
+        errors::RWAError,
+        states::{GlobalConfig, InvestorKYC},
+        utils::{ProgramAccount, ProgramAccountInit},
+    },
+};
+
+// spl-transfer-hook-interface instruction discriminators
+pub const EXECUTE_DISCRIMINATOR: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
+pub const INITIALIZE_EXTRA_ACCOUNT_METAS_DISCRIMINATOR: [u8; 8] = [43, 34, 13, 49, 167, 88, 235, 235];
+
+// ExtraAccountMeta: discriminator u8 | address config [u8; 32] | is_signer u8 | is_writable u8
+const EXTRA_ACCOUNT_META_LEN: usize = 35;
+const EXTRA_ACCOUNT_META_FIXED: u8 = 0;
+const EXTRA_ACCOUNT_META_PDA: u8 = 1;
+// Seed encodings inside a PDA address config
+const SEED_LITERAL: u8 = 1;
+const SEED_ACCOUNT_DATA: u8 = 4;
+
+// Execute accounts: source, mint, destination, authority, extra_account_metas, then the metas below
+const SOURCE_INDEX: u8 = 0;
+const DESTINATION_INDEX: u8 = 2;
+const TOKEN_ACCOUNT_OWNER_OFFSET: u8 = 32;
+const EXTRA_ACCOUNT_METAS_COUNT: usize = 4;
+
+/// The validation account Token-2022 reads to resolve the Execute accounts:
+/// global_config, token_config, sender InvestorKYC, receiver InvestorKYC
+pub struct ExtraAccountMetas;
+
+impl ExtraAccountMetas {
+    pub const SEED_PREFIX: &'static [u8] = b"extra-account-metas";
+    // TLV: Execute discriminator | value length u32 | PodSlice count u32 | metas
+    pub const LEN: usize = 8 + 4 + 4 + EXTRA_ACCOUNT_META_LEN * EXTRA_ACCOUNT_METAS_COUNT;
+
+    /// Checks `account` is the validation PDA of `mint` and returns its bump
+    pub fn check_pda(account: &AccountInfo, mint: &Pubkey, program_id: &Pubkey) -> Result<u8, ProgramError> {
+        let (expected_pda, bump) = find_program_address(&[Self::SEED_PREFIX, mint.as_ref()], program_id);
+        if *account.key() != expected_pda {
+            return Err(RWAError::PdaMismatch.into());
+        }
+        Ok(bump)
+    }
+
+    pub fn init(
+        payer: &AccountInfo,
+        account: &AccountInfo,
+        mint: &Pubkey,
+        token_config: &Pubkey,
+        program_id: &Pubkey,
+    ) -> ProgramResult {
+        let bump = Self::check_pda(account, mint, program_id)?;
+        let bump_ref = [bump];
+        let seeds = seeds!(Self::SEED_PREFIX, mint.as_ref(), &bump_ref);
+        ProgramAccount::init(payer, account, &seeds, Self::LEN)?;
+
+        let mut data = account.try_borrow_mut_data()?;
+        Self::write(&mut data, token_config);
+        Ok(())
+    }
+
+    /// Serializes the Execute TLV entry into `data`, which must be at least `LEN` bytes
+    pub fn write(data: &mut [u8], token_config: &Pubkey) {
+        data[..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+        data[8..12].copy_from_slice(&((4 + EXTRA_ACCOUNT_META_LEN * EXTRA_ACCOUNT_METAS_COUNT) as u32).to_le_bytes());
+        data[12..16].copy_from_slice(&(EXTRA_ACCOUNT_METAS_COUNT as u32).to_le_bytes());
+
+        let metas = [
+            Self::pda_meta(&[&[SEED_LITERAL, GlobalConfig::SEED_PREFIX.len() as u8], GlobalConfig::SEED_PREFIX]),
+            Self::fixed_meta(token_config),
+            Self::investor_kyc_meta(SOURCE_INDEX),
+            Self::investor_kyc_meta(DESTINATION_INDEX),
+        ];
+        for (meta, chunk) in metas.iter().zip(data[16..].chunks_exact_mut(EXTRA_ACCOUNT_META_LEN)) {
+            chunk.copy_from_slice(meta);
+        }
+    }
+
+    fn fixed_meta(address: &Pubkey) -> [u8; EXTRA_ACCOUNT_META_LEN] {
+        let mut meta = [0u8; EXTRA_ACCOUNT_META_LEN];
+        meta[0] = EXTRA_ACCOUNT_META_FIXED;
+        meta[1..33].copy_from_slice(address);
+        meta
+    }
+
+    /// Read-only PDA of this program, `parts` are concatenated into the address config
+    fn pda_meta(parts: &[&[u8]]) -> [u8; EXTRA_ACCOUNT_META_LEN] {
+        let mut meta = [0u8; EXTRA_ACCOUNT_META_LEN];
+        meta[0] = EXTRA_ACCOUNT_META_PDA;
+        let mut offset = 1;
+        for part in parts {
+            meta[offset..offset + part.len()].copy_from_slice(part);
+            offset += part.len();
+        }
+        meta
+    }
+
+    /// `[investor-kyc, owner of the token account at token_account_index]`
+    fn investor_kyc_meta(token_account_index: u8) -> [u8; EXTRA_ACCOUNT_META_LEN] {
+        Self::pda_meta(&[
+            &[SEED_LITERAL, InvestorKYC::SEED_PREFIX.len() as u8],
+            InvestorKYC::SEED_PREFIX,
+            &[SEED_ACCOUNT_DATA, token_account_index, TOKEN_ACCOUNT_OWNER_OFFSET, 32],
+        ])
+    }
+}